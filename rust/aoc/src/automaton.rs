@@ -0,0 +1,546 @@
+//! Life-like cellular automata, configured by a topology, a birth/survival rule and a storage.
+//!
+//! Sparse storage only keeps the live cells and works on unbounded topologies, as long as no cell
+//! is born without live neighbours. Dense storage keeps every cell of a finite topology.
+
+use crate::grid::Grid;
+use crate::Error;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// How cells are connected.
+pub trait Topology {
+    type Cell: Clone + Eq + Hash;
+
+    fn neighbours(&self, cell: &Self::Cell) -> Vec<Self::Cell>;
+}
+
+/// A topology with a fixed number of positions, which dense storage can index.
+pub trait Finite: Topology {
+    fn size(&self) -> usize;
+
+    /// The position of a cell, `None` if it is not part of the topology.
+    fn index(&self, cell: &Self::Cell) -> Option<usize>;
+
+    /// The cell at a position, `None` for positions that can never be alive.
+    fn cell(&self, index: usize) -> Option<Self::Cell>;
+}
+
+/// The eight surrounding positions on a bounded grid, only counting the positions that are cells.
+#[derive(Clone, Debug)]
+pub struct SquareGrid {
+    width: usize,
+    height: usize,
+    cells: Vec<bool>,
+}
+
+/// The first cell visible in each of the eight directions on a bounded grid.
+#[derive(Clone, Debug)]
+pub struct LineOfSight {
+    grid: SquareGrid,
+}
+
+/// An unbounded lattice of `N` dimensions, where all `3^N - 1` surrounding cells are neighbours.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Lattice<const N: usize>;
+
+/// An unbounded hexagonal grid in axial coordinates, with `(1, 0)` east and `(0, 1)` north-east.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HexGrid;
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl SquareGrid {
+    /// Use the positions of a grid for which `is_cell` holds as cells.
+    pub fn from_grid<T, F: Fn(&T) -> bool>(grid: &Grid<T>, is_cell: F) -> SquareGrid {
+        return SquareGrid {
+            width: grid.width(),
+            height: grid.height(),
+            cells: grid.iter().map(|(_, c)| is_cell(c)).collect(),
+        };
+    }
+
+    pub fn new(width: usize, height: usize) -> SquareGrid {
+        return SquareGrid {
+            width,
+            height,
+            cells: vec![true; width * height],
+        };
+    }
+
+    fn position(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        return Some(y as usize * self.width + x as usize);
+    }
+}
+
+impl Topology for SquareGrid {
+    type Cell = (isize, isize);
+
+    fn neighbours(&self, cell: &(isize, isize)) -> Vec<(isize, isize)> {
+        return DIRECTIONS
+            .iter()
+            .map(|(dx, dy)| (cell.0 + dx, cell.1 + dy))
+            .filter(|n| self.index(n).is_some())
+            .collect();
+    }
+}
+
+impl Finite for SquareGrid {
+    fn size(&self) -> usize {
+        return self.cells.len();
+    }
+
+    fn index(&self, cell: &(isize, isize)) -> Option<usize> {
+        return self.position(cell.0, cell.1).filter(|i| self.cells[*i]);
+    }
+
+    fn cell(&self, index: usize) -> Option<(isize, isize)> {
+        if !self.cells[index] {
+            return None;
+        }
+        return Some(((index % self.width) as isize, (index / self.width) as isize));
+    }
+}
+
+impl LineOfSight {
+    pub fn from_grid<T, F: Fn(&T) -> bool>(grid: &Grid<T>, is_cell: F) -> LineOfSight {
+        return LineOfSight {
+            grid: SquareGrid::from_grid(grid, is_cell),
+        };
+    }
+}
+
+impl Topology for LineOfSight {
+    type Cell = (isize, isize);
+
+    fn neighbours(&self, cell: &(isize, isize)) -> Vec<(isize, isize)> {
+        let mut neighbours = Vec::new();
+        for (dx, dy) in DIRECTIONS.iter() {
+            let (mut x, mut y) = (cell.0 + dx, cell.1 + dy);
+            // Look past the positions that are not cells, until leaving the grid.
+            while let Some(i) = self.grid.position(x, y) {
+                if self.grid.cells[i] {
+                    neighbours.push((x, y));
+                    break;
+                }
+                x += dx;
+                y += dy;
+            }
+        }
+        return neighbours;
+    }
+}
+
+impl Finite for LineOfSight {
+    fn size(&self) -> usize {
+        return self.grid.size();
+    }
+
+    fn index(&self, cell: &(isize, isize)) -> Option<usize> {
+        return self.grid.index(cell);
+    }
+
+    fn cell(&self, index: usize) -> Option<(isize, isize)> {
+        return self.grid.cell(index);
+    }
+}
+
+impl<const N: usize> Topology for Lattice<N> {
+    type Cell = [isize; N];
+
+    fn neighbours(&self, cell: &[isize; N]) -> Vec<[isize; N]> {
+        let mut neighbours = vec![*cell];
+        for d in 0..N {
+            neighbours = neighbours
+                .iter()
+                .flat_map(|n| {
+                    (-1..=1).map(move |delta| {
+                        let mut n = *n;
+                        n[d] += delta;
+                        n
+                    })
+                })
+                .collect();
+        }
+        neighbours.retain(|n| n != cell);
+        return neighbours;
+    }
+}
+
+impl Topology for HexGrid {
+    type Cell = (isize, isize);
+
+    fn neighbours(&self, cell: &(isize, isize)) -> Vec<(isize, isize)> {
+        return [(1, 0), (-1, 0), (0, 1), (0, -1), (1, -1), (-1, 1)]
+            .iter()
+            .map(|(dx, dy)| (cell.0 + dx, cell.1 + dy))
+            .collect();
+    }
+}
+
+/// The numbers of live neighbours for which a dead cell is born or a live cell survives.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    pub fn new(birth: &[usize], survival: &[usize]) -> Rule {
+        return Rule {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+        };
+    }
+
+    /// The rule of Conway's Game of Life, B3/S23.
+    pub fn conway() -> Rule {
+        return Rule::new(&[3], &[2, 3]);
+    }
+
+    pub fn next(&self, alive: bool, neighbours: usize) -> bool {
+        return match alive {
+            true => self.survival.contains(&neighbours),
+            false => self.birth.contains(&neighbours),
+        };
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// Parse the `B3/S23` notation, counts above nine are separated by commas like `B3/S2,3,10`.
+    fn from_str(input: &str) -> Result<Rule, Self::Err> {
+        let expected = "a rule like \"B3/S23\"";
+        let (birth, survival) = match input
+            .split_once('/')
+            .and_then(|(b, s)| Some((b.strip_prefix('B')?, s.strip_prefix('S')?)))
+        {
+            Some(t) => t,
+            None => return Err(Error::parse(1, 1, input, expected)),
+        };
+
+        let counts = |text: &str, column: usize| -> Result<Vec<usize>, Error> {
+            let digits: Vec<&str> = match text.contains(',') {
+                true => text.split(',').collect(),
+                false => text.split_terminator("").skip(1).collect(),
+            };
+            return digits
+                .iter()
+                .map(|d| {
+                    d.parse()
+                        .map_err(|_| Error::parse(1, column, text, "neighbour counts"))
+                })
+                .collect();
+        };
+        return Ok(Rule {
+            birth: counts(birth, 2)?,
+            survival: counts(survival, birth.len() + 4)?,
+        });
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let join = |counts: &[usize]| {
+            let separator = if counts.iter().any(|c| *c > 9) {
+                ","
+            } else {
+                ""
+            };
+            counts
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join(separator)
+        };
+        write!(f, "B{}/S{}", join(&self.birth), join(&self.survival))
+    }
+}
+
+/// The state of an automaton.
+pub trait Storage<T: Topology>: Clone + PartialEq {
+    fn new<I: IntoIterator<Item = T::Cell>>(topology: &T, alive: I) -> Self;
+
+    fn is_alive(&self, topology: &T, cell: &T::Cell) -> bool;
+
+    fn population(&self) -> usize;
+
+    fn alive(&self, topology: &T) -> Vec<T::Cell>;
+
+    fn step(&self, topology: &T, rule: &Rule) -> Self;
+}
+
+/// Keeps only the live cells, for unbounded topologies.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sparse<C: Eq + Hash> {
+    alive: HashSet<C>,
+}
+
+impl<T: Topology> Storage<T> for Sparse<T::Cell> {
+    fn new<I: IntoIterator<Item = T::Cell>>(_topology: &T, alive: I) -> Self {
+        return Sparse {
+            alive: alive.into_iter().collect(),
+        };
+    }
+
+    fn is_alive(&self, _topology: &T, cell: &T::Cell) -> bool {
+        return self.alive.contains(cell);
+    }
+
+    fn population(&self) -> usize {
+        return self.alive.len();
+    }
+
+    fn alive(&self, _topology: &T) -> Vec<T::Cell> {
+        return self.alive.iter().cloned().collect();
+    }
+
+    fn step(&self, topology: &T, rule: &Rule) -> Self {
+        assert!(
+            !rule.next(false, 0),
+            "Sparse storage cannot birth cells without live neighbours"
+        );
+
+        // Only cells next to a live cell can be alive in the next generation.
+        let mut counts: HashMap<T::Cell, usize> = HashMap::new();
+        for cell in &self.alive {
+            counts.entry(cell.clone()).or_insert(0);
+            for neighbour in topology.neighbours(cell) {
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        return Sparse {
+            alive: counts
+                .into_iter()
+                .filter(|(cell, count)| rule.next(self.alive.contains(cell), *count))
+                .map(|(cell, _)| cell)
+                .collect(),
+        };
+    }
+}
+
+/// Keeps every position of a finite topology, with the neighbours looked up once.
+#[derive(Clone, Debug)]
+pub struct Dense {
+    alive: Vec<bool>,
+    neighbours: Arc<Vec<Vec<usize>>>,
+}
+
+impl PartialEq for Dense {
+    fn eq(&self, other: &Self) -> bool {
+        return self.alive == other.alive;
+    }
+}
+
+impl<T: Finite> Storage<T> for Dense {
+    fn new<I: IntoIterator<Item = T::Cell>>(topology: &T, alive: I) -> Self {
+        let neighbours = (0..topology.size())
+            .map(|i| match topology.cell(i) {
+                Some(cell) => topology
+                    .neighbours(&cell)
+                    .iter()
+                    .filter_map(|n| topology.index(n))
+                    .collect(),
+                None => Vec::new(),
+            })
+            .collect();
+
+        let mut state = vec![false; topology.size()];
+        for cell in alive {
+            if let Some(i) = topology.index(&cell) {
+                state[i] = true;
+            }
+        }
+
+        return Dense {
+            alive: state,
+            neighbours: Arc::new(neighbours),
+        };
+    }
+
+    fn is_alive(&self, topology: &T, cell: &T::Cell) -> bool {
+        return topology.index(cell).is_some_and(|i| self.alive[i]);
+    }
+
+    fn population(&self) -> usize {
+        return self.alive.iter().filter(|a| **a).count();
+    }
+
+    fn alive(&self, topology: &T) -> Vec<T::Cell> {
+        return (0..self.alive.len())
+            .filter(|i| self.alive[*i])
+            .filter_map(|i| topology.cell(i))
+            .collect();
+    }
+
+    fn step(&self, topology: &T, rule: &Rule) -> Self {
+        let alive = (0..self.alive.len())
+            .map(|i| {
+                if topology.cell(i).is_none() {
+                    return false;
+                }
+                let count = self.neighbours[i]
+                    .iter()
+                    .filter(|n| self.alive[**n])
+                    .count();
+                rule.next(self.alive[i], count)
+            })
+            .collect();
+
+        return Dense {
+            alive,
+            neighbours: self.neighbours.clone(),
+        };
+    }
+}
+
+pub struct Automaton<T: Topology, S: Storage<T>> {
+    topology: T,
+    rule: Rule,
+    state: S,
+    generation: usize,
+}
+
+pub type SparseAutomaton<T> = Automaton<T, Sparse<<T as Topology>::Cell>>;
+pub type DenseAutomaton<T> = Automaton<T, Dense>;
+
+impl<T: Topology, S: Storage<T>> Automaton<T, S> {
+    pub fn new<I: IntoIterator<Item = T::Cell>>(topology: T, rule: Rule, alive: I) -> Self {
+        let state = S::new(&topology, alive);
+        return Automaton {
+            topology,
+            rule,
+            state,
+            generation: 0,
+        };
+    }
+
+    pub fn generation(&self) -> usize {
+        return self.generation;
+    }
+
+    pub fn population(&self) -> usize {
+        return self.state.population();
+    }
+
+    pub fn is_alive(&self, cell: &T::Cell) -> bool {
+        return self.state.is_alive(&self.topology, cell);
+    }
+
+    pub fn alive(&self) -> Vec<T::Cell> {
+        return self.state.alive(&self.topology);
+    }
+
+    /// Advance a single generation, returns whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let next = self.state.step(&self.topology, &self.rule);
+        let changed = next != self.state;
+        self.state = next;
+        self.generation += 1;
+        return changed;
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Step until nothing changes, returns the generation it became stable in, or `None` if it
+    /// still changed after `limit` steps.
+    pub fn run_until_stable(&mut self, limit: usize) -> Option<usize> {
+        for _ in 0..limit {
+            if !self.step() {
+                return Some(self.generation - 1);
+            }
+        }
+        return None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules() {
+        let rule: Rule = "B3/S23".parse().unwrap();
+        assert_eq!(rule, Rule::conway());
+        assert!(rule.next(false, 3));
+        assert!(!rule.next(false, 2));
+        assert!(rule.next(true, 2));
+        assert_eq!(rule.to_string(), "B3/S23");
+
+        let rule: Rule = "B0/S0,1,2,10".parse().unwrap();
+        assert_eq!(rule, Rule::new(&[0], &[0, 1, 2, 10]));
+        assert_eq!(rule.to_string(), "B0/S0,1,2,10");
+
+        assert!("B3S23".parse::<Rule>().is_err());
+        assert!("B3/Sx".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn blinker() {
+        // A blinker oscillates, so it is never stable.
+        let mut life: SparseAutomaton<Lattice<2>> =
+            Automaton::new(Lattice, Rule::conway(), vec![[0, -1], [0, 0], [0, 1]]);
+        life.step();
+        let mut alive = life.alive();
+        alive.sort();
+        assert_eq!(alive, vec![[-1, 0], [0, 0], [1, 0]]);
+        assert_eq!(life.run_until_stable(10), None);
+    }
+
+    #[test]
+    fn dense_matches_sparse() {
+        let glider = vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let mut dense: DenseAutomaton<SquareGrid> =
+            Automaton::new(SquareGrid::new(8, 8), Rule::conway(), glider.clone());
+        let mut sparse: SparseAutomaton<Lattice<2>> = Automaton::new(
+            Lattice,
+            Rule::conway(),
+            glider.iter().map(|(x, y)| [*x, *y]),
+        );
+
+        dense.run(4);
+        sparse.run(4);
+        assert_eq!(dense.generation(), 4);
+        assert_eq!(dense.population(), 5);
+        for [x, y] in sparse.alive() {
+            assert!(dense.is_alive(&(x, y)));
+        }
+    }
+
+    #[test]
+    fn line_of_sight() {
+        let grid: Grid<char> = "#.#\n...\n..#\n".parse().unwrap();
+        let topology = LineOfSight::from_grid(&grid, |c| *c == '#');
+        let mut neighbours = topology.neighbours(&(0, 0));
+        neighbours.sort();
+        assert_eq!(neighbours, vec![(2, 0), (2, 2)]);
+        assert_eq!(topology.cell(1), None);
+    }
+
+    #[test]
+    fn hexagons() {
+        assert_eq!(HexGrid.neighbours(&(0, 0)).len(), 6);
+        assert_eq!(Lattice::<4>.neighbours(&[0; 4]).len(), 80);
+    }
+}
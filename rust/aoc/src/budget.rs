@@ -0,0 +1,240 @@
+//! Limits and progress reports for long running solvers.
+//!
+//! A [`Budget`] caps the number of steps of a loop and the time a solver may take, and can be
+//! cancelled from another thread. The timeout is a deadline shared by every loop tracked on the
+//! budget and its clones, so it limits a whole solve rather than each loop. Solvers count their
+//! steps on a [`Tracker`] from [`current`], which fails once the budget is spent and reports
+//! progress along the way. Runners set the budget for the solvers they call with [`with`].
+
+use crate::{info, Error};
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often to look at the clock and the cancel flag, as a mask on the number of steps.
+const CHECK_MASK: u64 = 0xfff;
+
+/// How far a solver got, passed to the progress callback.
+#[derive(Clone, Debug)]
+pub struct Progress {
+    /// What the steps are, e.g. "turns".
+    pub what: &'static str,
+    pub done: u64,
+    pub total: Option<u64>,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.total {
+            Some(t) if t > 0 => write!(
+                f,
+                "{} of {} {} ({:.0}%)",
+                self.done,
+                t,
+                self.what,
+                100.0 * self.done as f64 / t as f64
+            ),
+            _ => write!(f, "{} {}", self.done, self.what),
+        }
+    }
+}
+
+type Callback = Arc<dyn Fn(&Progress) + Send + Sync>;
+
+/// Limits for a solver, unlimited by default. Clones share the cancel flag.
+#[derive(Clone, Default)]
+pub struct Budget {
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
+    max_steps: Option<u64>,
+    interval: Option<Duration>,
+    progress: Option<Callback>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Budget {
+    pub fn new() -> Budget {
+        return Budget::default();
+    }
+
+    /// Stop every tracked loop once `timeout` has passed since this call.
+    pub fn timeout(mut self, timeout: Duration) -> Budget {
+        self.timeout = Some(timeout);
+        self.deadline = Some(Instant::now() + timeout);
+        return self;
+    }
+
+    /// A clone with its timeout counting from now, for the next of several solves.
+    pub fn restart(&self) -> Budget {
+        let mut budget = self.clone();
+        budget.deadline = self.timeout.map(|t| Instant::now() + t);
+        return budget;
+    }
+
+    /// Stop a tracked loop after `max_steps` steps.
+    pub fn max_steps(mut self, max_steps: u64) -> Budget {
+        self.max_steps = Some(max_steps);
+        return self;
+    }
+
+    /// Report progress every `interval`, to `callback` instead of the log if one is given.
+    pub fn progress(mut self, interval: Duration, callback: Option<Callback>) -> Budget {
+        self.interval = Some(interval);
+        self.progress = callback;
+        return self;
+    }
+
+    /// Stop every loop tracked on this budget or its clones at their next check.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Start counting the steps of a loop, `total` is the number expected if known.
+    pub fn track(&self, what: &'static str, total: Option<u64>) -> Tracker {
+        let start = Instant::now();
+        return Tracker {
+            budget: self.clone(),
+            progress: Progress {
+                what,
+                done: 0,
+                total,
+                elapsed: Duration::default(),
+            },
+            start,
+            next_report: self.interval.map(|i| start + i),
+        };
+    }
+}
+
+/// The steps taken by one loop, see [`Budget::track`].
+pub struct Tracker {
+    budget: Budget,
+    progress: Progress,
+    start: Instant,
+    next_report: Option<Instant>,
+}
+
+impl Tracker {
+    /// Count a step, fails once the budget is spent or cancelled.
+    pub fn step(&mut self) -> Result<(), Error> {
+        if let Some(max) = self.budget.max_steps {
+            if self.progress.done >= max {
+                return Err(self.stopped(&format!("the limit of {} steps", max)));
+            }
+        }
+        self.progress.done += 1;
+        if self.progress.done & CHECK_MASK != 0 {
+            return Ok(());
+        }
+        return self.check();
+    }
+
+    /// Look at the clock and the cancel flag, for loops with few but slow steps.
+    pub fn check(&mut self) -> Result<(), Error> {
+        let now = Instant::now();
+        self.progress.elapsed = now - self.start;
+
+        if self.budget.cancelled.load(Ordering::Relaxed) {
+            return Err(self.stopped("a cancel"));
+        }
+        if let (Some(deadline), Some(timeout)) = (self.budget.deadline, self.budget.timeout) {
+            if now > deadline {
+                return Err(self.stopped(&format!("the timeout of {:?}", timeout)));
+            }
+        }
+        if let (Some(next), Some(interval)) = (self.next_report, self.budget.interval) {
+            if now >= next {
+                match &self.budget.progress {
+                    Some(callback) => callback(&self.progress),
+                    None => info!("{} after {:.1?}", self.progress, self.progress.elapsed),
+                }
+                self.next_report = Some(now + interval);
+            }
+        }
+        return Ok(());
+    }
+
+    fn stopped(&self, reason: &str) -> Error {
+        return Error::Stopped(format!("{} after {}", reason, self.progress));
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Budget> = RefCell::new(Budget::default());
+}
+
+/// The budget of the solver running on this thread, unlimited outside of [`with`].
+pub fn current() -> Budget {
+    return CURRENT.with(|c| c.borrow().clone());
+}
+
+/// Run `f` with `budget` as the current budget of this thread.
+pub fn with<T, F: FnOnce() -> T>(budget: Budget, f: F) -> T {
+    let previous = CURRENT.with(|c| c.replace(budget));
+    let result = f();
+    CURRENT.with(|c| c.replace(previous));
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn count(budget: Budget, steps: u64) -> Result<u64, Error> {
+        let mut tracker = budget.track("steps", Some(steps));
+        for _ in 0..steps {
+            tracker.step()?;
+        }
+        return Ok(steps);
+    }
+
+    #[test]
+    fn limits() {
+        assert_eq!(count(Budget::new(), 10000).unwrap(), 10000);
+        assert_eq!(count(Budget::new().max_steps(100), 100).unwrap(), 100);
+        assert_eq!(
+            count(Budget::new().max_steps(100), 1000)
+                .unwrap_err()
+                .to_string(),
+            "stopped: the limit of 100 steps after 100 of 1000 steps (10%)"
+        );
+        assert!(count(Budget::new().timeout(Duration::from_secs(0)), 100000).is_err());
+
+        let budget = Budget::new();
+        budget.clone().cancel();
+        assert!(count(budget, 100000).is_err());
+    }
+
+    #[test]
+    fn shared_timeout() {
+        let budget = Budget::new().timeout(Duration::from_millis(20));
+        assert!(budget.track("steps", None).check().is_ok());
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(budget.track("steps", None).check().is_err());
+        assert!(budget.restart().track("steps", None).check().is_ok());
+    }
+
+    #[test]
+    fn progress() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let budget = Budget::new().progress(
+            Duration::from_secs(0),
+            Some(Arc::new(move |p: &Progress| {
+                sink.lock().unwrap().push(p.done)
+            })),
+        );
+        count(budget, 3 * (CHECK_MASK + 1)).unwrap();
+        assert_eq!(*reports.lock().unwrap(), vec![4096, 8192, 12288]);
+    }
+
+    #[test]
+    fn current_budget() {
+        assert!(with(Budget::new().max_steps(5), || count(current(), 10)).is_err());
+        assert!(count(current(), 10).is_ok());
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+    NoSolution(String),
+    /// An input with more than one answer where the puzzle expects exactly one.
+    Ambiguous(String),
+    /// A puzzle parameter that does not exist or has an invalid value.
+    Param(String),
+    /// A solver that ran out of its budget, see `aoc::budget`.
+    Stopped(String),
+}
+
+impl Error {
+    /// Create a parse error, `line` and `column` are one-based.
+    pub fn parse(line: usize, column: usize, text: &str, expected: &str) -> Error {
+        return Error::Parse {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        };
+    }
+
+    /// Shift the line of a parse error, for errors raised on a slice of the input.
+    pub fn offset(self, lines: usize) -> Error {
+        return match self {
+            Error::Parse {
+                line,
+                column,
+                text,
+                expected,
+            } => Error::Parse {
+                line: line + lines,
+                column,
+                text,
+                expected,
+            },
+            e => e,
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "could not read input: {}", e),
+            Error::NoSolution(reason) => write!(f, "no solution: {}", reason),
+            Error::Ambiguous(reason) => write!(f, "ambiguous: {}", reason),
+            Error::Param(reason) => write!(f, "bad parameter: {}", reason),
+            Error::Stopped(reason) => write!(f, "stopped: {}", reason),
+            Error::Parse {
+                line,
+                column,
+                text,
+                expected,
+            } => {
+                if text.is_empty() {
+                    write!(f, "line {}, column {}: expected {}", line, column, expected)
+                } else {
+                    write!(
+                        f,
+                        "line {}, column {}: unexpected \"{}\", expected {}",
+                        line, column, text, expected
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        return Error::Io(e);
+    }
+}
+
+/// Parse `text` found at `line` and `column` of the input.
+pub fn parse_at<T: FromStr>(
+    text: &str,
+    line: usize,
+    column: usize,
+    expected: &str,
+) -> Result<T, Error> {
+    return text
+        .parse()
+        .map_err(|_| Error::parse(line, column, text, expected));
+}
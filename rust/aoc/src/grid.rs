@@ -0,0 +1,280 @@
+use crate::{parse_lines_from_str, Error};
+use std::fmt;
+use std::str::FromStr;
+
+/// A dense rectangular grid, `x` is the column and `y` the row counted from the top left.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells in row order, panics if the number of cells does not fit.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        return Grid {
+            width,
+            height,
+            cells,
+        };
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+        return Grid::new(width, height, cells);
+    }
+
+    /// Parse one cell per character, rows are lines and all need to have the same length.
+    pub fn parse_with<F: Fn(char) -> Option<T>>(
+        input: &str,
+        expected: &str,
+        f: F,
+    ) -> Result<Grid<T>, Error> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+            // Allow trailing blank lines.
+            if line.is_empty() && input.lines().skip(i).all(|l| l.trim().is_empty()) {
+                break;
+            }
+
+            let mut row_width = 0;
+            for (j, c) in line.chars().enumerate() {
+                match f(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(Error::parse(i + 1, j + 1, &c.to_string(), expected)),
+                }
+                row_width += 1;
+            }
+
+            if i == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(Error::parse(
+                    i + 1,
+                    row_width.min(width) + 1,
+                    "",
+                    &format!("a row of {} cells", width),
+                ));
+            }
+            height += 1;
+        }
+
+        return Ok(Grid::new(width, height, cells));
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        return Some(y as usize * self.width + x as usize);
+    }
+
+    /// Get a cell, or `None` outside of the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        return self.index(x, y).map(|i| &self.cells[i]);
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        return self.index(x, y).map(move |i| &mut self.cells[i]);
+    }
+
+    /// Get a cell on a toroidal map, coordinates outside of the grid wrap around.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize);
+        let y = y.rem_euclid(self.height as isize);
+        return &self.cells[y as usize * self.width + x as usize];
+    }
+
+    /// Replace a cell, returns false if it is outside of the grid.
+    pub fn set(&mut self, x: isize, y: isize, value: T) -> bool {
+        return match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        };
+    }
+
+    /// Iterate over all cells in row order, with their `(x, y)` position.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        return self
+            .cells
+            .iter()
+            .enumerate()
+            .map(move |(i, c)| ((i % width, i / width), c));
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return (0..self.height).map(move |y| self.row(y));
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside of the grid", x);
+        return self.cells.iter().skip(x).step_by(self.width);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(move |x| self.column(x));
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        return Grid::new(self.width, self.height, self.cells.iter().map(f).collect());
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        return Grid::new(width, height, vec![value; width * height]);
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate(&self) -> Grid<T> {
+        return Grid::from_fn(self.height, self.width, |x, y| {
+            self.cells[(self.height - 1 - x) * self.width + y].clone()
+        });
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        return Grid::from_fn(self.width, self.height, |x, y| {
+            self.cells[y * self.width + self.width - 1 - x].clone()
+        });
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        return Grid::from_fn(self.width, self.height, |x, y| {
+            self.cells[(self.height - 1 - y) * self.width + x].clone()
+        });
+    }
+
+    /// All eight orientations reachable by rotating and flipping, starting with this one.
+    pub fn orientations(&self) -> Vec<Grid<T>> {
+        let mut orientations = Vec::new();
+        let mut grid = self.clone();
+        for _ in 0..4 {
+            orientations.push(grid.clone());
+            orientations.push(grid.flip_horizontal());
+            grid = grid.rotate();
+        }
+        return orientations;
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Grid<char>, Self::Err> {
+        return Grid::parse_with(input, "a character", Some);
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        let grid: Grid<char> = "#..\n.#.\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(1, 1), Some(&'#'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get_wrapping(4, -1), &'#');
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Grid::parse_with("..\n.x\n", "'.' or '#'", |c| match c {
+            '.' | '#' => Some(c),
+            _ => None,
+        })
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected \"x\", expected '.' or '#'"
+        );
+
+        let error = "...\n..\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a row of 3 cells"
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            grid.rows().collect::<Vec<&[i32]>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(grid.column(1).copied().collect::<Vec<i32>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)));
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(grid.rotate(), Grid::new(2, 3, vec![4, 1, 5, 2, 6, 3]));
+        assert_eq!(
+            grid.flip_horizontal(),
+            Grid::new(3, 2, vec![3, 2, 1, 6, 5, 4])
+        );
+        assert_eq!(
+            grid.flip_vertical(),
+            Grid::new(3, 2, vec![4, 5, 6, 1, 2, 3])
+        );
+        assert_eq!(grid.rotate().rotate().rotate().rotate(), grid);
+
+        let orientations = grid.orientations();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert!(orientations.contains(&grid.flip_vertical()));
+    }
+}
@@ -1,45 +1,182 @@
-use regex::Regex;
-use std::io::{self, BufRead, Read};
+pub mod automaton;
+pub mod budget;
+mod error;
+pub mod grid;
+pub mod log;
+pub mod math;
+pub mod parallel;
+pub mod params;
+pub mod record;
+mod solution;
+pub mod stream;
+
+pub use error::{parse_at, Error};
+pub use solution::{configure, main, Part, Registry, Runnable, Solution};
 
-pub fn parse_ints() -> Vec<isize> {
-    let mut vec = Vec::new();
-    let re = Regex::new(r"([-+]?\d+)\D?").unwrap();
+use regex::Regex;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+use std::process;
 
+/// Read all of stdin, for binaries that do more than print the answers.
+pub fn read_stdin() -> Result<String, Error> {
     // Get the stdin and read it into a buffer.
     let mut buffer = String::new();
     let mut stdin = io::stdin();
-    match stdin.read_to_string(&mut buffer) {
-        Ok(n) => println!("Parsed {}", n),
-        Err(_) => panic!("Could not read from stdin"),
-    };
+    let n = stdin.read_to_string(&mut buffer)?;
+    debug!("Parsed {} bytes from stdin", n);
+
+    return Ok(buffer);
+}
+
+fn read_to_string<R: Read>(mut reader: R) -> Result<String, Error> {
+    let mut buffer = String::new();
+    reader.read_to_string(&mut buffer)?;
+    return Ok(buffer);
+}
+
+/// Find the one-based line and column of a byte offset in the input.
+fn locate(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+    return (line, column);
+}
 
-    for cap in re.captures_iter(&buffer) {
-        vec.push(cap[1].parse().unwrap());
+/// Report an error on stderr and exit, for use in the binaries.
+pub fn unwrap_or_exit<T>(result: Result<T, Error>) -> T {
+    match result {
+        Ok(t) => t,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
     }
+}
+
+/// Integers are separated by any other character, and a sign right after a number is taken as
+/// that separator, so `3-4` holds 3 and 4. The streaming reader shares the pattern, so it is
+/// applied to a line at a time as well as to a whole input.
+fn int_pattern() -> Regex {
+    return Regex::new(r"([-+]?\d+)\D?").unwrap();
+}
+
+/// The integers in a text, see [`int_pattern`].
+fn int_tokens<'t>(pattern: &'t Regex, text: &'t str) -> impl Iterator<Item = regex::Match<'t>> {
+    return pattern.captures_iter(text).filter_map(|cap| cap.get(1));
+}
+
+pub fn parse_ints() -> Result<Vec<isize>, Error> {
+    return parse_ints_from_str(&read_stdin()?);
+}
+
+pub fn parse_ints_from_str(input: &str) -> Result<Vec<isize>, Error> {
+    let pattern = int_pattern();
+    return int_tokens(&pattern, input)
+        .map(|m| {
+            // Only failures need a position, so a valid input is scanned once.
+            m.as_str().parse().or_else(|_| {
+                let (line, column) = locate(input, m.start());
+                parse_at(m.as_str(), line, column, "an integer")
+            })
+        })
+        .collect();
+}
 
-    return vec;
+pub fn parse_ints_from_reader<R: Read>(reader: R) -> Result<Vec<isize>, Error> {
+    return parse_ints_from_str(&read_to_string(reader)?);
 }
 
-pub fn parse_lines() -> Vec<String> {
-    let mut vec = Vec::new();
+pub fn parse_ints_from_file<P: AsRef<Path>>(path: P) -> Result<Vec<isize>, Error> {
+    return parse_ints_from_reader(File::open(path)?);
+}
 
+pub fn parse_lines() -> Result<Vec<String>, Error> {
     let stdin = io::stdin();
-    for line in stdin.lock().lines() {
-        let line = line.expect("Could not read line from standard in");
-        vec.push(line);
-    }
+    return parse_lines_from_reader(stdin.lock());
+}
 
-    return vec;
+pub fn parse_lines_from_str(input: &str) -> Result<Vec<String>, Error> {
+    return Ok(input.lines().map(|s| s.to_string()).collect());
 }
 
-pub fn parse_blocks() -> Vec<String> {
-    // Get the stdin and read it into a buffer.
-    let mut buffer = String::new();
-    let mut stdin = io::stdin();
-    match stdin.read_to_string(&mut buffer) {
-        Ok(n) => println!("Parsed {}", n),
-        Err(_) => panic!("Could not read from stdin"),
-    };
+pub fn parse_lines_from_reader<R: BufRead>(reader: R) -> Result<Vec<String>, Error> {
+    return Ok(reader.lines().collect::<Result<_, _>>()?);
+}
+
+pub fn parse_lines_from_file<P: AsRef<Path>>(path: P) -> Result<Vec<String>, Error> {
+    return parse_lines_from_reader(BufReader::new(File::open(path)?));
+}
+
+pub fn parse_blocks() -> Result<Vec<String>, Error> {
+    return parse_blocks_from_str(&read_stdin()?);
+}
+
+pub fn parse_blocks_from_str(input: &str) -> Result<Vec<String>, Error> {
+    return Ok(input.split("\n\n").map(|s| s.to_string()).collect());
+}
+
+pub fn parse_blocks_from_reader<R: Read>(reader: R) -> Result<Vec<String>, Error> {
+    return parse_blocks_from_str(&read_to_string(reader)?);
+}
+
+pub fn parse_blocks_from_file<P: AsRef<Path>>(path: P) -> Result<Vec<String>, Error> {
+    return parse_blocks_from_reader(File::open(path)?);
+}
 
-    return buffer.split("\n\n").map(|s| s.to_string()).collect();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_from_str() {
+        assert_eq!(
+            parse_ints_from_str("1\n-2\n+3,4").unwrap(),
+            vec![1, -2, 3, 4]
+        );
+        assert_eq!(parse_ints_from_str("3-4\n-5").unwrap(), vec![3, 4, -5]);
+    }
+
+    #[test]
+    fn ints_overflow() {
+        let error = parse_ints_from_str("1\n2, 99999999999999999999999").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: unexpected \"99999999999999999999999\", expected an integer"
+        );
+    }
+
+    #[test]
+    fn ints_from_reader() {
+        assert_eq!(
+            parse_ints_from_reader("35\n20\n15\n".as_bytes()).unwrap(),
+            vec![35, 20, 15]
+        );
+    }
+
+    #[test]
+    fn lines_from_reader() {
+        assert_eq!(
+            parse_lines_from_reader("abc\n\na\nb\r\n".as_bytes()).unwrap(),
+            vec!["abc", "", "a", "b"]
+        );
+        assert_eq!(
+            parse_lines_from_str("abc\n\na\nb\r\n").unwrap(),
+            vec!["abc", "", "a", "b"]
+        );
+    }
+
+    #[test]
+    fn blocks_from_reader() {
+        assert_eq!(
+            parse_blocks_from_reader("a\nb\n\nc\n".as_bytes()).unwrap(),
+            vec!["a\nb", "c\n"]
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        assert!(parse_lines_from_file("does/not/exist.txt").is_err());
+    }
 }
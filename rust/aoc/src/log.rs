@@ -0,0 +1,195 @@
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Environment variable holding the verbosity, e.g. `AOC_LOG=debug`.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn from_usize(value: usize) -> Option<Level> {
+        return match value {
+            1 => Some(Level::Error),
+            2 => Some(Level::Warn),
+            3 => Some(Level::Info),
+            4 => Some(Level::Debug),
+            5 => Some(Level::Trace),
+            _ => None,
+        };
+    }
+
+    fn from_name(name: &str) -> Option<Option<Level>> {
+        return match name.to_lowercase().as_str() {
+            "off" => Some(None),
+            "error" => Some(Some(Level::Error)),
+            "warn" => Some(Some(Level::Warn)),
+            "info" => Some(Some(Level::Info)),
+            "debug" => Some(Some(Level::Debug)),
+            "trace" => Some(Some(Level::Trace)),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// A single diagnostic, `target` is the module it was raised in.
+pub struct Record<'a> {
+    pub level: Level,
+    pub target: &'a str,
+    pub args: fmt::Arguments<'a>,
+}
+
+type Sink = Box<dyn Fn(&Record) + Send>;
+
+// Zero means nothing is logged, otherwise this is a `Level` discriminant.
+static MAX_LEVEL: AtomicUsize = AtomicUsize::new(Level::Warn as usize);
+static SINK: Mutex<Option<Sink>> = Mutex::new(None);
+
+pub fn max_level() -> Option<Level> {
+    return Level::from_usize(MAX_LEVEL.load(Ordering::Relaxed));
+}
+
+pub fn set_max_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |l| l as usize), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    return (level as usize) <= MAX_LEVEL.load(Ordering::Relaxed);
+}
+
+/// Replace the default stderr output, e.g. to collect the records in a test.
+pub fn set_sink<F: Fn(&Record) + Send + 'static>(sink: F) {
+    *SINK.lock().unwrap() = Some(Box::new(sink));
+}
+
+pub fn log(record: &Record) {
+    if !enabled(record.level) {
+        return;
+    }
+
+    match SINK.lock().unwrap().as_ref() {
+        Some(sink) => sink(record),
+        None => {
+            let _ = writeln!(
+                io::stderr(),
+                "[{:<5} {}] {}",
+                record.level,
+                record.target,
+                record.args
+            );
+        }
+    }
+}
+
+/// Set the verbosity from `AOC_LOG`, then from `-v`/`-q` flags which raise and lower it a level.
+pub fn init() {
+    if let Ok(name) = env::var(ENV_VAR) {
+        match Level::from_name(&name) {
+            Some(level) => set_max_level(level),
+            None => crate::warn!("Ignoring unknown {} value {:?}", ENV_VAR, name),
+        }
+    }
+
+    let mut verbosity = MAX_LEVEL.load(Ordering::Relaxed) as isize;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "-q" | "--quiet" => verbosity -= 1,
+            "-v" | "--verbose" => verbosity += 1,
+            a if a.len() > 1 && a.starts_with('-') && a[1..].chars().all(|c| c == 'v') => {
+                verbosity += (a.len() - 1) as isize
+            }
+            _ => {}
+        }
+    }
+    set_max_level(Level::from_usize(
+        verbosity.clamp(0, Level::Trace as isize) as usize
+    ));
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::log(&$crate::log::Record {
+                level: $level,
+                target: module_path!(),
+                args: format_args!($($arg)+),
+            });
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn records_below_max_level() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink_records = records.clone();
+        set_sink(move |r: &Record| {
+            sink_records
+                .lock()
+                .unwrap()
+                .push(format!("{} {} {}", r.level, r.target, r.args))
+        });
+        set_max_level(Some(Level::Info));
+
+        crate::info!("Loaded {} tiles", 9);
+        crate::debug!("Not shown");
+
+        assert_eq!(
+            *records.lock().unwrap(),
+            vec!["INFO aoc::log::tests Loaded 9 tiles"]
+        );
+        assert_eq!(max_level(), Some(Level::Info));
+    }
+}
@@ -0,0 +1,198 @@
+//! Number theory on `i128`, moduli have to be positive and results are in `0..modulus`.
+
+use std::collections::HashMap;
+
+/// The greatest common divisor, never negative.
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    return a;
+}
+
+/// The least common multiple, `None` if it does not fit.
+pub fn lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    return (a / gcd(a, b)).checked_mul(b).map(|l| l.abs());
+}
+
+pub fn gcd_all(values: &[i128]) -> i128 {
+    return values.iter().fold(0, |g, v| gcd(g, *v));
+}
+
+/// The least common multiple of all values, 1 for none and `None` if it does not fit.
+pub fn lcm_all(values: &[i128]) -> Option<i128> {
+    return values.iter().try_fold(1, |l, v| lcm(l, *v));
+}
+
+/// The extended Euclidean algorithm, returns `(g, x, y)` with `a * x + b * y = g`.
+pub fn egcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        return (-old_r, -old_x, -old_y);
+    }
+    return (old_r, old_x, old_y);
+}
+
+/// Multiply without overflowing, even when the product of the operands does not fit.
+pub fn mod_mul(a: i128, b: i128, modulus: i128) -> i128 {
+    let (mut a, mut b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    // Double and add, every intermediate value stays below twice the modulus.
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = mod_add(result, a, modulus);
+        }
+        a = mod_add(a, a, modulus);
+        b >>= 1;
+    }
+    return result;
+}
+
+fn mod_add(a: i128, b: i128, modulus: i128) -> i128 {
+    // Both are below the modulus, so subtracting first cannot overflow.
+    return if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    };
+}
+
+/// Exponentiation by squaring.
+pub fn mod_pow(base: i128, mut exponent: u128, modulus: i128) -> i128 {
+    let mut base = base.rem_euclid(modulus);
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    return result;
+}
+
+/// The multiplicative inverse, `None` if the value and the modulus are not coprime.
+pub fn mod_inv(value: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = egcd(value.rem_euclid(modulus), modulus);
+    if g != 1 {
+        return None;
+    }
+    return Some(x.rem_euclid(modulus));
+}
+
+/// Solve a system of congruences `x = residue (mod modulus)`, the moduli need not be coprime.
+///
+/// Returns the smallest solution with the combined modulus, or `None` if the congruences
+/// contradict each other or the combined modulus does not fit.
+pub fn chinese_remainder(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut x = 0;
+    let mut m = 1;
+
+    for (residue, modulus) in congruences {
+        let residue = residue.rem_euclid(*modulus);
+        let (g, p, _) = egcd(m, *modulus);
+        if (residue - x) % g != 0 {
+            return None;
+        }
+
+        // Step x by multiples of m until it also matches this congruence.
+        let combined = lcm(m, *modulus)?;
+        let k = mod_mul((residue - x) / g, p, modulus / g);
+        x = (x + mod_mul(m, k, combined)).rem_euclid(combined);
+        m = combined;
+    }
+    return Some((x, m));
+}
+
+/// The smallest `x` with `base^x = target (mod modulus)`, using baby-step giant-step.
+///
+/// The base has to be coprime with the modulus, `None` if there is no such `x`.
+pub fn discrete_log(base: i128, target: i128, modulus: i128) -> Option<i128> {
+    let target = target.rem_euclid(modulus);
+    let steps = (modulus as f64).sqrt().ceil() as i128 + 1;
+
+    // Remember the first exponent of every baby step.
+    let mut baby = HashMap::new();
+    let mut value = 1 % modulus;
+    for j in 0..steps {
+        baby.entry(value).or_insert(j);
+        value = mod_mul(value, base, modulus);
+    }
+
+    // Take giant steps of base^-steps from the target.
+    let factor = mod_pow(mod_inv(base, modulus)?, steps as u128, modulus);
+    let mut gamma = target;
+    for i in 0..steps {
+        if let Some(j) = baby.get(&gamma) {
+            return Some(i * steps + j);
+        }
+        gamma = mod_mul(gamma, factor, modulus);
+    }
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divisors() {
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd_all(&[12, 18, 27]), 3);
+        assert_eq!(lcm_all(&[4, 6, 10]), Some(60));
+        assert_eq!(lcm(i128::MAX, i128::MAX - 1), None);
+
+        let (g, x, y) = egcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let large = i128::MAX - 2;
+        assert_eq!(mod_mul(large - 1, large - 1, large), 1);
+        assert_eq!(mod_mul(-3, 5, 7), 6);
+        assert_eq!(mod_pow(7, 8, 20201227), 5764801);
+        assert_eq!(mod_pow(2, 0, 1), 0);
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(4, 12), None);
+    }
+
+    #[test]
+    fn congruences() {
+        assert_eq!(
+            chinese_remainder(&[(2, 3), (3, 5), (2, 7)]),
+            Some((23, 105))
+        );
+        // Not coprime, but consistent.
+        assert_eq!(chinese_remainder(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn logarithms() {
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(2, 1, 7), Some(0));
+        assert_eq!(discrete_log(2, 3, 7), None);
+    }
+}
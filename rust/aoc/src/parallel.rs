@@ -0,0 +1,144 @@
+//! Searches over independent items that run on a thread pool with the `parallel` feature.
+//!
+//! Without the feature every function runs on the calling thread. Either way the results come
+//! back in the order of the items, so answers and logs do not depend on the scheduling. The
+//! [`budget`](crate::budget) of the calling thread is passed on to the threads that do the work.
+
+use crate::budget;
+#[cfg(feature = "parallel")]
+use std::collections::BTreeMap;
+#[cfg(feature = "parallel")]
+use std::sync::Mutex;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Apply `f` to every item, the results are in the order of the items.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    let current = budget::current();
+    let f = |item: &T| budget::with(current.clone(), || f(item));
+
+    #[cfg(feature = "parallel")]
+    return items.par_iter().map(f).collect();
+    #[cfg(not(feature = "parallel"))]
+    return items.iter().map(f).collect();
+}
+
+/// Apply `f` to every item and pass the results to `emit` in the order of the items, each one as
+/// soon as it and all results before it are done.
+pub fn map_ordered<T, R, F, E>(items: &[T], f: F, emit: E)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+    E: FnMut(&T, R) + Send,
+{
+    let current = budget::current();
+    let f = |item: &T| budget::with(current.clone(), || f(item));
+
+    #[cfg(feature = "parallel")]
+    {
+        // The index of the next result to emit, and the later ones that are done already.
+        let ready = Mutex::new((0, BTreeMap::new(), emit));
+        items.par_iter().enumerate().for_each(|(i, item)| {
+            let result = f(item);
+            let mut ready = ready.lock().unwrap();
+            let (next, waiting, emit) = &mut *ready;
+            waiting.insert(i, result);
+            while let Some(result) = waiting.remove(next) {
+                emit(&items[*next], result);
+                *next += 1;
+            }
+        });
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        let mut emit = emit;
+        for item in items {
+            emit(item, f(item));
+        }
+    }
+}
+
+/// The first result of `f` in the order of the items that is not `None`.
+pub fn find_map_first<T, R, F>(items: &[T], f: F) -> Option<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Option<R> + Sync + Send,
+{
+    let current = budget::current();
+    let f = |item: &T| budget::with(current.clone(), || f(item));
+
+    #[cfg(feature = "parallel")]
+    return items.par_iter().find_map_first(f);
+    #[cfg(not(feature = "parallel"))]
+    return items.iter().find_map(f);
+}
+
+/// Run `f` with the searches above, and `map`s nested in it, on a pool of `threads` threads.
+pub fn with_threads<T, F>(threads: usize, f: F) -> T
+where
+    T: Send,
+    F: FnOnce() -> T + Send,
+{
+    #[cfg(feature = "parallel")]
+    return rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("failed to start the thread pool")
+        .install(f);
+    #[cfg(not(feature = "parallel"))]
+    {
+        let _ = threads;
+        return f();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budget::Budget;
+
+    #[test]
+    fn ordered() {
+        let items: Vec<u64> = (0..1000).collect();
+        let squares = with_threads(4, || map(&items, |i| i * i));
+        assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<u64>>());
+        assert_eq!(
+            with_threads(4, || find_map_first(&items, |i| Some(*i)
+                .filter(|i| i % 7 == 6))),
+            Some(6)
+        );
+        assert_eq!(find_map_first(&items, |_| None::<u64>), None);
+
+        let mut emitted = Vec::new();
+        with_threads(4, || {
+            map_ordered(&items, |i| i * i, |i, s| emitted.push((*i, s)))
+        });
+        assert_eq!(
+            emitted,
+            items
+                .iter()
+                .map(|i| (*i, i * i))
+                .collect::<Vec<(u64, u64)>>()
+        );
+    }
+
+    #[test]
+    fn budget_is_passed_on() {
+        let items = vec![10, 1000];
+        let results = budget::with(Budget::new().max_steps(100), || {
+            map(&items, |n| {
+                let mut tracker = budget::current().track("steps", None);
+                return (0..*n).try_for_each(|_| tracker.step()).is_ok();
+            })
+        });
+        assert_eq!(results, vec![true, false]);
+    }
+}
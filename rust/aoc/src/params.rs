@@ -0,0 +1,73 @@
+//! Puzzle parameters, the constants from a puzzle description that can be varied without editing
+//! the solutions, like the target sum of day 01.
+//!
+//! Days expose them through [`crate::Solution::params`] and [`crate::Solution::set_param`],
+//! values are given as text on the command line or in a file.
+
+use crate::Error;
+use std::env;
+use std::str::FromStr;
+
+/// Parse the value of a parameter, `expected` describes a valid value.
+pub fn parse_param<T: FromStr>(name: &str, value: &str, expected: &str) -> Result<T, Error> {
+    return value
+        .parse()
+        .map_err(|_| Error::Param(format!("{} = {:?}, expected {}", name, value, expected)));
+}
+
+/// The error for a parameter that a day does not have, listing the ones it does have.
+pub fn unknown_param(name: &str, params: &[(&'static str, String)]) -> Error {
+    if params.is_empty() {
+        return Error::Param(format!("unknown parameter {:?}, there are none", name));
+    }
+    let known: Vec<&str> = params.iter().map(|(n, _)| *n).collect();
+    return Error::Param(format!(
+        "unknown parameter {:?}, expected one of {}",
+        name,
+        known.join(", ")
+    ));
+}
+
+/// Split an assignment like `preamble=5` into its name and value.
+pub fn split_assignment(assignment: &str) -> Result<(String, String), Error> {
+    return match assignment.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(Error::Param(format!(
+            "{:?}, expected an assignment like <name>=<value>",
+            assignment
+        ))),
+    };
+}
+
+/// The assignments given after every `--param` on the command line.
+pub fn arguments() -> Result<Vec<(String, String)>, Error> {
+    let args: Vec<String> = env::args().collect();
+    return args
+        .windows(2)
+        .filter(|w| w[0] == "--param")
+        .map(|w| split_assignment(&w[1]))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assignments() {
+        assert_eq!(
+            split_assignment("preamble = 5").unwrap(),
+            ("preamble".to_string(), "5".to_string())
+        );
+        assert!(split_assignment("preamble").is_err());
+        assert!(split_assignment("=5").is_err());
+
+        let error = parse_param::<usize>("preamble", "five", "a number").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bad parameter: preamble = \"five\", expected a number"
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! Declarative parsing of line based records.
+//!
+//! A [`Format`] is a line pattern with named fields, like `{x:int}-{y:int} {letter:char}`.
+//! Fields match any text unless they are given a kind: `int`, `char` or `word`. Types implement
+//! [`FromInput`] to be built from the fields, and [`parse_records`] reads one record per line.
+
+use crate::{parse_lines_from_str, Error};
+use regex::Regex;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Any,
+    Int,
+    Char,
+    Word,
+}
+
+impl Kind {
+    fn from_name(name: &str) -> Option<Kind> {
+        return match name {
+            "" => Some(Kind::Any),
+            "int" => Some(Kind::Int),
+            "char" => Some(Kind::Char),
+            "word" => Some(Kind::Word),
+            _ => None,
+        };
+    }
+
+    fn pattern(&self) -> &'static str {
+        return match self {
+            Kind::Any => ".+?",
+            Kind::Int => r"[-+]?\d+",
+            Kind::Char => ".",
+            Kind::Word => r"\w+",
+        };
+    }
+}
+
+/// One or more alternative line patterns.
+#[derive(Clone, Debug)]
+pub struct Format {
+    alternatives: Vec<(Regex, Vec<(String, Kind)>)>,
+    expected: String,
+}
+
+impl Format {
+    /// Compile a pattern, panics on unknown kinds or unclosed fields.
+    pub fn new(pattern: &str) -> Format {
+        let (regex, fields, description) = Format::compile(pattern);
+        return Format {
+            alternatives: vec![(regex, fields)],
+            expected: format!("a line like \"{}\"", description),
+        };
+    }
+
+    /// Also accept lines of another pattern, see [`Fields::alternative`].
+    pub fn or(mut self, pattern: &str) -> Format {
+        let (regex, fields, description) = Format::compile(pattern);
+        self.alternatives.push((regex, fields));
+        self.expected = format!(
+            "{} or \"{}\"",
+            self.expected.replacen("a line like ", "", 1),
+            description
+        );
+        return self;
+    }
+
+    /// Describe the format in errors for lines that do not match.
+    pub fn expected(mut self, expected: &str) -> Format {
+        self.expected = expected.to_string();
+        return self;
+    }
+
+    /// The regex of a pattern, its fields, and a description with the fields as `<name>`.
+    fn compile(pattern: &str) -> (Regex, Vec<(String, Kind)>, String) {
+        let mut regex = "^".to_string();
+        let mut fields = Vec::new();
+        let mut description = String::new();
+
+        let mut rest = pattern;
+        while let Some(start) = rest.find('{') {
+            regex.push_str(&regex::escape(&rest[..start]));
+            description.push_str(&rest[..start]);
+            let end = rest[start..]
+                .find('}')
+                .unwrap_or_else(|| panic!("Unclosed field in format {:?}", pattern));
+            let field = &rest[start + 1..start + end];
+
+            let (name, kind) = field.split_once(':').unwrap_or((field, ""));
+            let kind = Kind::from_name(kind)
+                .unwrap_or_else(|| panic!("Unknown kind of field {:?} in {:?}", field, pattern));
+            regex.push_str(&format!("({})", kind.pattern()));
+            description.push_str(&format!("<{}>", name));
+            fields.push((name.to_string(), kind));
+            rest = &rest[start + end + 1..];
+        }
+        regex.push_str(&regex::escape(rest));
+        regex.push('$');
+        description.push_str(rest);
+
+        return (Regex::new(&regex).unwrap(), fields, description);
+    }
+
+    /// Match a line, `number` is its one-based line number used in errors.
+    pub fn parse<'a>(&self, line: &'a str, number: usize) -> Result<Fields<'a>, Error> {
+        for (alternative, (regex, names)) in self.alternatives.iter().enumerate() {
+            if let Some(captures) = regex.captures(line) {
+                let values = names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (name, kind))| {
+                        let m = captures.get(i + 1).unwrap();
+                        let column = line[..m.start()].chars().count() + 1;
+                        (name.clone(), *kind, m.as_str(), column)
+                    })
+                    .collect();
+                return Ok(Fields {
+                    line: number,
+                    alternative,
+                    values,
+                });
+            }
+        }
+        return Err(Error::parse(number, 1, line, &self.expected));
+    }
+}
+
+/// The fields of a matched line, with their one-based character column for errors.
+#[derive(Clone, Debug)]
+pub struct Fields<'a> {
+    line: usize,
+    alternative: usize,
+    values: Vec<(String, Kind, &'a str, usize)>,
+}
+
+impl<'a> Fields<'a> {
+    /// The index of the pattern that matched, 0 for the one given to [`Format::new`].
+    pub fn alternative(&self) -> usize {
+        return self.alternative;
+    }
+
+    /// The number of the line the fields were parsed from.
+    pub fn line(&self) -> usize {
+        return self.line;
+    }
+
+    fn field(&self, name: &str) -> &(String, Kind, &'a str, usize) {
+        return self
+            .values
+            .iter()
+            .find(|v| v.0 == name)
+            .unwrap_or_else(|| panic!("There is no field {:?} in this format", name));
+    }
+
+    /// The text of a field, panics if the matched pattern has no such field.
+    pub fn str(&self, name: &str) -> &'a str {
+        return self.field(name).2;
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, Error> {
+        let (_, kind, text, column) = self.field(name);
+        return text.parse().map_err(|_| {
+            let expected = match kind {
+                Kind::Int => "a number".to_string(),
+                _ => format!("a valid value for {}", name),
+            };
+            Error::parse(self.line, *column, text, &expected)
+        });
+    }
+
+    /// Split a field and parse every item.
+    pub fn list<T: FromStr>(&self, name: &str, separator: &str) -> Result<Vec<T>, Error> {
+        let (_, _, text, column) = self.field(name);
+        let mut items = Vec::new();
+        let mut offset = 0;
+        for item in text.split(separator) {
+            items.push(item.parse().map_err(|_| {
+                Error::parse(
+                    self.line,
+                    column + offset,
+                    item,
+                    &format!("a valid item of {}", name),
+                )
+            })?);
+            offset += item.chars().count() + separator.chars().count();
+        }
+        return Ok(items);
+    }
+
+    /// An error about the value of a field.
+    pub fn error(&self, name: &str, expected: &str) -> Error {
+        let (_, _, text, column) = self.field(name);
+        return Error::parse(self.line, *column, text, expected);
+    }
+}
+
+/// A record that is parsed from a single line.
+pub trait FromInput: Sized {
+    fn format() -> Format;
+
+    fn from_fields(fields: &Fields) -> Result<Self, Error>;
+}
+
+/// Parse every line into a record, failing on the first line that does not match.
+pub fn parse_records<T: FromInput>(input: &str) -> Result<Vec<T>, Error> {
+    let format = T::format();
+    let mut records = Vec::new();
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        records.push(T::from_fields(&format.parse(line, i + 1)?)?);
+    }
+    return Ok(records);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Policy {
+        low: usize,
+        high: usize,
+        letter: char,
+        password: String,
+    }
+
+    impl FromInput for Policy {
+        fn format() -> Format {
+            return Format::new("{low:int}-{high:int} {letter:char}: {password}");
+        }
+
+        fn from_fields(fields: &Fields) -> Result<Policy, Error> {
+            return Ok(Policy {
+                low: fields.get("low")?,
+                high: fields.get("high")?,
+                letter: fields.get("letter")?,
+                password: fields.get("password")?,
+            });
+        }
+    }
+
+    #[test]
+    fn records() {
+        let policies: Vec<Policy> = parse_records("1-3 a: abcde\n2-9 c: ccccc\n").unwrap();
+        assert_eq!(policies.len(), 2);
+        assert_eq!(
+            policies[0],
+            Policy {
+                low: 1,
+                high: 3,
+                letter: 'a',
+                password: "abcde".to_string()
+            }
+        );
+
+        let error = parse_records::<Policy>("1-3 a: abcde\n1 a: b\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected \"1 a: b\", expected a line like \
+             \"<low>-<high> <letter>: <password>\""
+        );
+
+        let error = parse_records::<Policy>("1-99999999999999999999 a: b\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: unexpected \"99999999999999999999\", expected a number"
+        );
+    }
+
+    #[test]
+    fn alternatives() {
+        let format = Format::new("mask = {mask:word}").or("mem[{address:int}] = {value:int}");
+        let fields = format.parse("mem[8] = 11", 1).unwrap();
+        assert_eq!(fields.alternative(), 1);
+        assert_eq!(fields.get::<usize>("value").unwrap(), 11);
+        assert_eq!(
+            format.parse("mem = 1", 3).unwrap_err().to_string(),
+            "line 3, column 1: unexpected \"mem = 1\", expected \"mask = <mask>\" or \
+             \"mem[<address>] = <value>\""
+        );
+    }
+
+    #[test]
+    fn lists() {
+        let format = Format::new("{ingredients} (contains {allergens})");
+        let fields = format.parse("a b (contains x, y)", 1).unwrap();
+        assert_eq!(
+            fields.list::<String>("allergens", ", ").unwrap(),
+            vec!["x", "y"]
+        );
+        let fields = Format::new("{values}").parse("1,x", 1).unwrap();
+        assert_eq!(
+            fields.list::<u8>("values", ",").unwrap_err().to_string(),
+            "line 1, column 3: unexpected \"x\", expected a valid item of values"
+        );
+    }
+
+    #[test]
+    fn columns() {
+        let fields = Format::new("{name} = {values}")
+            .parse("ü = 1,x", 7)
+            .unwrap();
+        assert_eq!(
+            fields.list::<u8>("values", ",").unwrap_err().to_string(),
+            "line 7, column 7: unexpected \"x\", expected a valid item of values"
+        );
+        assert_eq!(
+            fields.get::<u8>("name").unwrap_err().to_string(),
+            "line 7, column 1: unexpected \"ü\", expected a valid value for name"
+        );
+        assert_eq!(
+            fields.error("values", "digits").to_string(),
+            "line 7, column 5: unexpected \"1,x\", expected digits"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::params::{self, unknown_param};
+use crate::{log, read_stdin, unwrap_or_exit, Error};
+use std::any::{type_name, Any};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "A"),
+            Part::B => write!(f, "B"),
+        }
+    }
+}
+
+/// A solver for one day, both parts share the input produced by `parse`.
+pub trait Solution {
+    type Input;
+    type AnswerA: Display;
+    type AnswerB: Display;
+
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error>;
+
+    fn part_a(&self, input: &Self::Input) -> Result<Self::AnswerA, Error>;
+
+    fn part_b(&self, input: &Self::Input) -> Result<Self::AnswerB, Error>;
+
+    /// The puzzle parameters with their current values, e.g. `("preamble", "25")` for day 09.
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return Vec::new();
+    }
+
+    /// Override one of the `params`, unknown names and invalid values are an error.
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), Error> {
+        return Err(unknown_param(name, &self.params()));
+    }
+}
+
+/// A `Solution` with its types erased, so days can be stored and invoked side by side.
+pub trait Runnable: Send + Sync {
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, Error>;
+
+    /// Run a part on the input returned by `parse`.
+    fn run(&self, input: &(dyn Any + Send + Sync), part: Part) -> Result<String, Error>;
+
+    /// The name of the answer type of a part, e.g. `usize` or `String`.
+    fn answer_type(&self, part: Part) -> &'static str;
+
+    fn params(&self) -> Vec<(&'static str, String)>;
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error>;
+
+    fn solve(&self, input: &str, part: Part) -> Result<String, Error> {
+        return self.run(self.parse(input)?.as_ref(), part);
+    }
+}
+
+impl<S> Runnable for S
+where
+    S: Solution + Send + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        return Solution::day(self);
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, Error> {
+        return Ok(Box::new(Solution::parse(self, input)?));
+    }
+
+    fn run(&self, input: &(dyn Any + Send + Sync), part: Part) -> Result<String, Error> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input should come from the parse of the same day");
+        return match part {
+            Part::A => Ok(self.part_a(input)?.to_string()),
+            Part::B => Ok(self.part_b(input)?.to_string()),
+        };
+    }
+
+    fn answer_type(&self, part: Part) -> &'static str {
+        let name = match part {
+            Part::A => type_name::<S::AnswerA>(),
+            Part::B => type_name::<S::AnswerB>(),
+        };
+        // Strip the module path, e.g. `alloc::string::String`.
+        return name.rsplit("::").next().unwrap_or(name);
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return Solution::params(self);
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        return Solution::set_param(self, name, value);
+    }
+}
+
+/// All known days, ordered by day number.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u8, Box<dyn Runnable>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        return Registry::default();
+    }
+
+    /// Add a day, replacing any solution registered earlier for the same day.
+    pub fn register<R: Runnable + 'static>(&mut self, solution: R) {
+        self.days.insert(solution.day(), Box::new(solution));
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn Runnable> {
+        return self.days.get(&day).map(|r| r.as_ref());
+    }
+
+    pub fn get_mut(&mut self, day: u8) -> Option<&mut (dyn Runnable + 'static)> {
+        return self.days.get_mut(&day).map(|r| r.as_mut());
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = &dyn Runnable> {
+        return self.days.values().map(|r| r.as_ref());
+    }
+}
+
+/// Override the parameters of a day with every `--param <name>=<value>` on the command line.
+pub fn configure<S: Solution>(solution: &mut S) -> Result<(), Error> {
+    for (name, value) in params::arguments()? {
+        solution.set_param(&name, &value)?;
+    }
+    return Ok(());
+}
+
+/// Solve both parts of a day on stdin, printing the answers.
+pub fn main<S: Solution>(mut solution: S) {
+    log::init();
+    unwrap_or_exit(configure(&mut solution));
+    let input = unwrap_or_exit(read_stdin().and_then(|s| solution.parse(&s)));
+    println!("A: {}", unwrap_or_exit(solution.part_a(&input)));
+    println!("B: {}", unwrap_or_exit(solution.part_b(&input)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::parse_param;
+    use crate::parse_ints_from_str;
+
+    struct Sum {
+        offset: isize,
+    }
+
+    impl Solution for Sum {
+        type Input = Vec<isize>;
+        type AnswerA = isize;
+        type AnswerB = String;
+
+        fn day(&self) -> u8 {
+            return 1;
+        }
+
+        fn parse(&self, input: &str) -> Result<Vec<isize>, Error> {
+            return parse_ints_from_str(input);
+        }
+
+        fn part_a(&self, input: &Vec<isize>) -> Result<isize, Error> {
+            return Ok(input.iter().sum::<isize>() + self.offset);
+        }
+
+        fn part_b(&self, _input: &Vec<isize>) -> Result<String, Error> {
+            return Err(Error::NoSolution("not implemented".to_string()));
+        }
+
+        fn params(&self) -> Vec<(&'static str, String)> {
+            return vec![("offset", self.offset.to_string())];
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+            match name {
+                "offset" => self.offset = parse_param(name, value, "an integer")?,
+                _ => return Err(unknown_param(name, &Solution::params(self))),
+            }
+            return Ok(());
+        }
+    }
+
+    #[test]
+    fn registry() {
+        let mut registry = Registry::new();
+        registry.register(Sum { offset: 0 });
+
+        let day = registry.get(1).unwrap();
+        assert_eq!(day.solve("1\n2\n3\n", Part::A).unwrap(), "6");
+        assert_eq!(
+            day.solve("1\n2\n3\n", Part::B).unwrap_err().to_string(),
+            "no solution: not implemented"
+        );
+        assert_eq!(day.answer_type(Part::A), "isize");
+        assert_eq!(day.answer_type(Part::B), "String");
+        assert!(registry.get(2).is_none());
+        assert_eq!(
+            registry.days().map(|d| d.day()).collect::<Vec<u8>>(),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn params() {
+        let mut registry = Registry::new();
+        registry.register(Sum { offset: 0 });
+
+        let day = registry.get_mut(1).unwrap();
+        day.set_param("offset", "10").unwrap();
+        assert_eq!(day.params(), vec![("offset", "10".to_string())]);
+        assert_eq!(day.solve("1\n2\n3\n", Part::A).unwrap(), "16");
+        assert_eq!(
+            day.set_param("scale", "2").unwrap_err().to_string(),
+            "bad parameter: unknown parameter \"scale\", expected one of offset"
+        );
+        assert!(day.set_param("offset", "ten").is_err());
+    }
+}
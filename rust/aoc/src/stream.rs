@@ -0,0 +1,174 @@
+//! Lazy readers that yield items as they are read, for inputs too large to hold in memory.
+//!
+//! Only the current line or block is buffered. Errors carry the line they were found on, like
+//! the parsers that read the whole input at once.
+
+use crate::{int_pattern, int_tokens, parse_at, Error};
+use regex::Regex;
+use std::collections::VecDeque;
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// The lines of a reader, without their line endings.
+pub struct Lines<R> {
+    reader: R,
+    buffer: String,
+}
+
+/// Every integer in a reader, see [`crate::parse_ints_from_str`].
+pub struct Ints<R> {
+    lines: Lines<R>,
+    line: usize,
+    pending: VecDeque<Result<isize, Error>>,
+    pattern: Regex,
+}
+
+/// The blocks of lines of a reader, separated by blank lines.
+pub struct Blocks<R> {
+    lines: Lines<R>,
+}
+
+pub fn lines<R: BufRead>(reader: R) -> Lines<R> {
+    return Lines {
+        reader,
+        buffer: String::new(),
+    };
+}
+
+pub fn ints<R: BufRead>(reader: R) -> Ints<R> {
+    return Ints {
+        lines: lines(reader),
+        line: 0,
+        pending: VecDeque::new(),
+        pattern: int_pattern(),
+    };
+}
+
+/// Unlike [`crate::parse_blocks_from_str`], blocks never end in a newline and runs of blank
+/// lines do not produce empty blocks.
+pub fn blocks<R: BufRead>(reader: R) -> Blocks<R> {
+    return Blocks {
+        lines: lines(reader),
+    };
+}
+
+/// Open a file for one of the readers.
+pub fn open<P: AsRef<Path>>(path: P) -> Result<BufReader<File>, Error> {
+    return Ok(BufReader::new(File::open(path)?));
+}
+
+/// The path given after `--stream` on the command line, if any.
+pub fn path_argument() -> Option<PathBuf> {
+    return env::args()
+        .skip_while(|a| a != "--stream")
+        .nth(1)
+        .map(PathBuf::from);
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.clear();
+        match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(e.into())),
+        }
+
+        let line = self.buffer.trim_end_matches('\n').trim_end_matches('\r');
+        return Some(Ok(line.to_string()));
+    }
+}
+
+impl<R: BufRead> Iterator for Ints<R> {
+    type Item = Result<isize, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Numbers never span lines, so parse a line at a time.
+        while self.pending.is_empty() {
+            let text = match self.lines.next()? {
+                Ok(t) => t,
+                Err(e) => return Some(Err(e)),
+            };
+            self.line += 1;
+
+            for m in int_tokens(&self.pattern, &text) {
+                let column = text[..m.start()].chars().count() + 1;
+                self.pending
+                    .push_back(parse_at(m.as_str(), self.line, column, "an integer"));
+            }
+        }
+        return self.pending.pop_front();
+    }
+}
+
+impl<R: BufRead> Iterator for Blocks<R> {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut block: Option<String> = None;
+        for line in &mut self.lines {
+            let line = match line {
+                Ok(l) => l,
+                Err(e) => return Some(Err(e)),
+            };
+
+            match (&mut block, line.is_empty()) {
+                (None, true) => continue,
+                (Some(_), true) => break,
+                (None, false) => block = Some(line),
+                (Some(b), false) => {
+                    b.push('\n');
+                    b.push_str(&line);
+                }
+            }
+        }
+        return block.map(Ok);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streamed_lines() {
+        let lines: Vec<String> = lines("abc\n\na\nb\r\n".as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(lines, vec!["abc", "", "a", "b"]);
+    }
+
+    #[test]
+    fn streamed_ints() {
+        let values: Vec<isize> = ints("1\n-2\n+3,4".as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(values, vec![1, -2, 3, 4]);
+
+        // Split like the parsers that read the whole input at once.
+        let text = "3-4\n-5\n6+-7";
+        let values: Vec<isize> = ints(text.as_bytes()).collect::<Result<_, _>>().unwrap();
+        assert_eq!(values, crate::parse_ints_from_str(text).unwrap());
+
+        let error = ints("1\n2, 99999999999999999999999".as_bytes())
+            .nth(2)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: unexpected \"99999999999999999999999\", expected an integer"
+        );
+    }
+
+    #[test]
+    fn streamed_blocks() {
+        let blocks: Vec<String> = blocks("a\nb\n\n\n\nc\n".as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(blocks, vec!["a\nb", "c"]);
+    }
+}
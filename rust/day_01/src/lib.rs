@@ -0,0 +1,529 @@
+use aoc::params::{parse_param, unknown_param};
+use aoc::{parse_at, parse_lines_from_str, stream, Error, Solution};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Find the entries that sum up to `target`.
+pub struct Day01 {
+    pub target: i32,
+}
+
+impl Default for Day01 {
+    fn default() -> Day01 {
+        return Day01 { target: 2020 };
+    }
+}
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type AnswerA = i64;
+    type AnswerB = i64;
+
+    fn day(&self) -> u8 {
+        return 1;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, Error> {
+        return parse_integers(input);
+    }
+
+    fn part_a(&self, inputs: &Vec<i32>) -> Result<i64, Error> {
+        return part_a(inputs, self.target);
+    }
+
+    fn part_b(&self, inputs: &Vec<i32>) -> Result<i64, Error> {
+        return part_b(inputs, self.target);
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return vec![("target", self.target.to_string())];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "target" => self.target = parse_param(name, value, "an integer")?,
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+fn parse_integers(input: &str) -> Result<Vec<i32>, Error> {
+    let mut vec = Vec::new();
+
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        vec.push(parse_at(line, i + 1, 1, "an integer")?);
+    }
+
+    return Ok(vec);
+}
+
+/// How `k_sum` finds the last two entries of a combination.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Search {
+    /// Look up the complement of each entry among the entries before it, in input order.
+    Hash,
+    /// Walk inwards from both ends of the entries sorted by value.
+    TwoPointer,
+}
+
+/// The indices of `k` different entries that sum to `target`, in ascending order.
+///
+/// An entry is used at most once, a value can only be used twice if it occurs twice. Fixing all
+/// but two entries and searching the rest takes O(n^(k-1)) time, plus a sort for `TwoPointer`.
+pub fn k_sum(entries: &[i32], k: usize, target: i32, search: Search) -> Option<Vec<usize>> {
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    if search == Search::TwoPointer {
+        order.sort_by_key(|&i| entries[i]);
+    }
+
+    let mut chosen = Vec::new();
+    if !k_sum_from(entries, &order, k, target as i64, search, &mut chosen) {
+        return None;
+    }
+    chosen.sort_unstable();
+    return Some(chosen);
+}
+
+/// Extend `chosen` with `k` of the entries at `order` summing to `target`, sums are in i64 so
+/// that they cannot overflow.
+fn k_sum_from(
+    entries: &[i32],
+    order: &[usize],
+    k: usize,
+    target: i64,
+    search: Search,
+    chosen: &mut Vec<usize>,
+) -> bool {
+    match k {
+        0 => return target == 0,
+        1 => match order.iter().find(|&&i| entries[i] as i64 == target) {
+            Some(&i) => {
+                chosen.push(i);
+                return true;
+            }
+            None => return false,
+        },
+        2 => match pair_sum(entries, order, target, search) {
+            Some((i, j)) => {
+                chosen.extend([i, j]);
+                return true;
+            }
+            None => return false,
+        },
+        _ => {}
+    }
+
+    for (p, &i) in order.iter().enumerate() {
+        chosen.push(i);
+        let rest = target - entries[i] as i64;
+        if k_sum_from(entries, &order[p + 1..], k - 1, rest, search, chosen) {
+            return true;
+        }
+        chosen.pop();
+    }
+    return false;
+}
+
+fn pair_sum(
+    entries: &[i32],
+    order: &[usize],
+    target: i64,
+    search: Search,
+) -> Option<(usize, usize)> {
+    match search {
+        Search::Hash => {
+            let mut seen: HashMap<i64, usize> = HashMap::new();
+            for &i in order {
+                let value = entries[i] as i64;
+                if let Some(&j) = seen.get(&(target - value)) {
+                    return Some((j, i));
+                }
+                seen.entry(value).or_insert(i);
+            }
+            return None;
+        }
+        Search::TwoPointer => {
+            if order.len() < 2 {
+                return None;
+            }
+            let (mut low, mut high) = (0, order.len() - 1);
+            while low < high {
+                let sum = entries[order[low]] as i64 + entries[order[high]] as i64;
+                if sum == target {
+                    return Some((order[low], order[high]));
+                }
+                if sum < target {
+                    low += 1;
+                } else {
+                    high -= 1;
+                }
+            }
+            return None;
+        }
+    }
+}
+
+/// Every distinct combination of `k` different entries that sums to `target`.
+///
+/// Combinations are distinct by their values, so repeated values do not repeat a combination.
+/// Each one is given as its indices in ascending order, the combinations come in ascending order
+/// of their values. Every but the last two entries is fixed in turn and the last two are found
+/// with two pointers, so listing them all takes O(n^(k-1)) time after a sort.
+pub fn k_sums(entries: &[i32], k: usize, target: i32) -> KSums<'_> {
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|&i| entries[i]);
+
+    let mut sums = KSums {
+        entries,
+        order,
+        target: target as i64,
+        prefix: (0..k.saturating_sub(2)).collect(),
+        low: 0,
+        high: 0,
+        single: None,
+        done: false,
+    };
+    match k {
+        0 => {
+            sums.single = Some(Vec::new()).filter(|_| target == 0);
+            sums.done = true;
+        }
+        1 => {
+            sums.single = entries.iter().position(|&e| e == target).map(|i| vec![i]);
+            sums.done = true;
+        }
+        _ if entries.len() < k => sums.done = true,
+        _ => sums.reset_pointers(),
+    }
+    return sums;
+}
+
+/// The iterator returned by [`k_sums`].
+pub struct KSums<'a> {
+    entries: &'a [i32],
+    /// The indices of the entries sorted by value, the positions below index into this.
+    order: Vec<usize>,
+    target: i64,
+    /// The positions of the entries fixed before the last two.
+    prefix: Vec<usize>,
+    low: usize,
+    high: usize,
+    /// The only combination when `k` is 0 or 1.
+    single: Option<Vec<usize>>,
+    done: bool,
+}
+
+impl KSums<'_> {
+    fn value(&self, position: usize) -> i64 {
+        return self.entries[self.order[position]] as i64;
+    }
+
+    fn reset_pointers(&mut self) {
+        self.low = self.prefix.last().map_or(0, |p| p + 1);
+        self.high = self.order.len() - 1;
+    }
+
+    /// Move the prefix to the next combination of positions with different values, leaving room
+    /// for the last two entries. Returns false when there is none.
+    fn advance_prefix(&mut self) -> bool {
+        let n = self.order.len();
+        let k = self.prefix.len() + 2;
+        for depth in (0..self.prefix.len()).rev() {
+            let current = self.prefix[depth];
+            let limit = n - (k - depth);
+            let mut next = current + 1;
+            while next <= limit && self.value(next) == self.value(current) {
+                next += 1;
+            }
+            if next <= limit {
+                for (offset, position) in self.prefix[depth..].iter_mut().enumerate() {
+                    *position = next + offset;
+                }
+                return true;
+            }
+        }
+        return false;
+    }
+}
+
+impl Iterator for KSums<'_> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if let Some(single) = self.single.take() {
+            return Some(single);
+        }
+
+        while !self.done {
+            let rest = self.target - self.prefix.iter().map(|&p| self.value(p)).sum::<i64>();
+            while self.low < self.high {
+                let sum = self.value(self.low) + self.value(self.high);
+                if sum < rest {
+                    self.low += 1;
+                } else if sum > rest {
+                    self.high -= 1;
+                } else {
+                    let mut indices: Vec<usize> =
+                        self.prefix.iter().map(|&p| self.order[p]).collect();
+                    indices.extend([self.order[self.low], self.order[self.high]]);
+                    indices.sort_unstable();
+
+                    // Skip past both values, so the same pair is not found again.
+                    let (low, high) = (self.value(self.low), self.value(self.high));
+                    while self.low < self.high && self.value(self.low) == low {
+                        self.low += 1;
+                    }
+                    while self.low < self.high && self.value(self.high) == high {
+                        self.high -= 1;
+                    }
+                    return Some(indices);
+                }
+            }
+
+            if self.advance_prefix() {
+                self.reset_pointers();
+            } else {
+                self.done = true;
+            }
+        }
+        return None;
+    }
+}
+
+/// The product of the only combination of `k` entries summing to `target`.
+fn product_of_sum(inputs: &[i32], k: usize, target: i32) -> Result<i64, Error> {
+    let mut sums = k_sums(inputs, k, target);
+    let indices = match (sums.next(), sums.count()) {
+        (Some(indices), 0) => indices,
+        (Some(_), more) => {
+            return Err(Error::Ambiguous(format!(
+                "{} combinations of {} entries sum to {}",
+                more + 1,
+                k,
+                target
+            )))
+        }
+        (None, _) => {
+            return Err(Error::NoSolution(format!(
+                "no {} entries sum to {}",
+                k, target
+            )))
+        }
+    };
+    return indices
+        .iter()
+        .try_fold(1i64, |product, &i| product.checked_mul(inputs[i] as i64))
+        .ok_or_else(|| Error::NoSolution(format!("the product of the {} entries overflows", k)));
+}
+
+fn part_a(inputs: &[i32], target: i32) -> Result<i64, Error> {
+    return product_of_sum(inputs, 2, target);
+}
+
+fn part_b(inputs: &[i32], target: i32) -> Result<i64, Error> {
+    return product_of_sum(inputs, 3, target);
+}
+
+/// Part A on a stream of numbers, keeping only the distinct numbers and pairs seen so far.
+///
+/// The whole stream is read, so that a second pair makes the answer ambiguous like in part A.
+pub fn find_pair<I>(ints: I, target: isize) -> Result<isize, Error>
+where
+    I: IntoIterator<Item = Result<isize, Error>>,
+{
+    let mut seen = HashSet::new();
+    let mut pairs = HashSet::new();
+
+    for int in ints {
+        let int = int?;
+        // A partner outside of the range of isize cannot be among the entries.
+        if let Some(partner) = target.checked_sub(int) {
+            if seen.contains(&partner) {
+                pairs.insert((int.min(partner), int.max(partner)));
+            }
+        }
+        seen.insert(int);
+    }
+
+    let (int, partner) = match pairs.len() {
+        0 => {
+            return Err(Error::NoSolution(format!(
+                "no two entries sum to {}",
+                target
+            )))
+        }
+        1 => pairs.into_iter().next().unwrap(),
+        more => {
+            return Err(Error::Ambiguous(format!(
+                "{} combinations of 2 entries sum to {}",
+                more, target
+            )))
+        }
+    };
+    return int
+        .checked_mul(partner)
+        .ok_or_else(|| Error::NoSolution("the product of the 2 entries overflows".to_string()));
+}
+
+/// Solve part A without holding the file in memory, part B needs all entries at once.
+pub fn solve_streaming(path: &Path, target: isize) -> Result<isize, Error> {
+    return find_pair(stream::ints(stream::open(path)?), target);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Entries below 1010 never sum to 2020, so the planted pair is the only one.
+    fn entries_with_pair() -> impl Strategy<Value = Vec<i32>> {
+        return (
+            0..1010i32,
+            prop::collection::vec(0..1010i32, 0..50),
+            any::<usize>(),
+        )
+            .prop_map(|(low, others, position)| {
+                let mut entries: Vec<i32> = others.into_iter().filter(|e| *e != low).collect();
+                entries.insert(position % (entries.len() + 1), 2020 - low);
+                entries.insert(0, low);
+                return entries;
+            });
+    }
+
+    /// Whether any `k` different entries sum to `target`, by trying every combination.
+    fn has_k_sum(entries: &[i32], k: usize, target: i64) -> bool {
+        if k == 0 {
+            return target == 0;
+        }
+        return (0..entries.len())
+            .any(|i| has_k_sum(&entries[i + 1..], k - 1, target - entries[i] as i64));
+    }
+
+    /// The values of every combination of `k` different entries summing to `target`.
+    fn all_k_sums(entries: &[i32], k: usize, target: i64) -> Vec<Vec<i32>> {
+        if k == 0 {
+            return if target == 0 {
+                vec![Vec::new()]
+            } else {
+                Vec::new()
+            };
+        }
+        let mut sums = Vec::new();
+        for i in 0..entries.len() {
+            for mut rest in all_k_sums(&entries[i + 1..], k - 1, target - entries[i] as i64) {
+                rest.push(entries[i]);
+                sums.push(rest);
+            }
+        }
+        return sums;
+    }
+
+    #[test]
+    fn example() {
+        let entries = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(k_sum(&entries, 2, 2020, Search::Hash), Some(vec![0, 3]));
+        assert_eq!(part_a(&entries, 2020).unwrap(), 514579);
+        assert_eq!(part_b(&entries, 2020).unwrap(), 241861950);
+    }
+
+    #[test]
+    fn multiplicity() {
+        for search in [Search::Hash, Search::TwoPointer] {
+            assert_eq!(k_sum(&[1010, 7], 2, 2020, search), None);
+            assert_eq!(k_sum(&[1010, 7, 1010], 2, 2020, search), Some(vec![0, 2]));
+            assert_eq!(k_sum(&[5, 5, 10], 3, 15, search), None);
+            assert_eq!(k_sum(&[5, 5, 5], 3, 15, search), Some(vec![0, 1, 2]));
+        }
+        assert_eq!(
+            part_b(&[1, 2], 2020).unwrap_err().to_string(),
+            "no solution: no 3 entries sum to 2020"
+        );
+    }
+
+    #[test]
+    fn ambiguous() {
+        let entries = [1000, 1020, 1010, 1010, 1000, 20];
+        let sums: Vec<Vec<usize>> = k_sums(&entries, 2, 2020).collect();
+        assert_eq!(sums, vec![vec![0, 1], vec![2, 3]]);
+        match part_a(&entries, 2020) {
+            Err(Error::Ambiguous(reason)) => {
+                assert_eq!(reason, "2 combinations of 2 entries sum to 2020")
+            }
+            other => panic!("expected an ambiguous answer, got {:?}", other),
+        }
+        assert_eq!(part_b(&entries, 2020).unwrap(), 20 * 1000 * 1000);
+
+        let streamed = entries.iter().map(|e| Ok(*e as isize));
+        assert_eq!(
+            find_pair(streamed, 2020).unwrap_err().to_string(),
+            "ambiguous: 2 combinations of 2 entries sum to 2020"
+        );
+    }
+
+    #[test]
+    fn large_products() {
+        assert_eq!(part_a(&[1000000, -997980], 2020).unwrap(), -997980000000);
+        assert_eq!(
+            part_b(&[i32::MAX, i32::MAX, i32::MIN], i32::MAX - 1)
+                .unwrap_err()
+                .to_string(),
+            "no solution: the product of the 3 entries overflows"
+        );
+        let streamed = vec![Ok(isize::MAX), Ok(-isize::MAX)];
+        assert!(find_pair(streamed, 0).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn k_sums_are_found(
+            entries in prop::collection::vec(-20..20i32, 0..12),
+            k in 0..5usize,
+            target in -40..40i32,
+        ) {
+            let exists = has_k_sum(&entries, k, target as i64);
+            for search in [Search::Hash, Search::TwoPointer] {
+                let found = k_sum(&entries, k, target, search);
+                prop_assert_eq!(found.is_some(), exists);
+                if let Some(indices) = found {
+                    prop_assert_eq!(indices.len(), k);
+                    prop_assert!(indices.windows(2).all(|w| w[0] < w[1]));
+                    prop_assert_eq!(indices.iter().map(|&i| entries[i]).sum::<i32>(), target);
+                }
+            }
+        }
+
+        #[test]
+        fn every_k_sum_is_listed(
+            entries in prop::collection::vec(-10..10i32, 0..10),
+            k in 0..5usize,
+            target in -20..20i32,
+        ) {
+            let mut expected: Vec<Vec<i32>> = all_k_sums(&entries, k, target as i64)
+                .into_iter()
+                .map(|mut values| {
+                    values.sort_unstable();
+                    return values;
+                })
+                .collect();
+            expected.sort();
+            expected.dedup();
+
+            let mut listed = Vec::new();
+            for indices in k_sums(&entries, k, target) {
+                prop_assert!(indices.windows(2).all(|w| w[0] < w[1]));
+                let mut values: Vec<i32> = indices.iter().map(|&i| entries[i]).collect();
+                values.sort_unstable();
+                listed.push(values);
+            }
+            listed.sort();
+            prop_assert_eq!(listed, expected);
+        }
+
+        #[test]
+        fn pair_strategies_agree(entries in entries_with_pair()) {
+            let streamed = entries.iter().map(|e| Ok(*e as isize));
+            prop_assert_eq!(find_pair(streamed, 2020).unwrap(), part_a(&entries, 2020).unwrap() as isize);
+        }
+    }
+}
@@ -1,43 +1,12 @@
-use std::io::{self, BufRead};
-
-fn parse_integers() -> Vec<i32> {
-    let mut vec = Vec::new();
-
-    let stdin = io::stdin();
-    for line in stdin.lock().lines() {
-        let line = line.expect("Could not read line from standard in");
-        vec.push(line.parse::<i32>().unwrap());
-    }
-
-    return vec;
-}
-
-fn part_a(inputs: &Vec<i32>) -> i32 {
-    for x in inputs {
-        for y in inputs {
-            if x + y == 2020 {
-                return x * y;
-            }
-        }
-    }
-    return -1;
-}
-
-fn part_b(inputs: &Vec<i32>) -> i32 {
-    for x in inputs {
-        for y in inputs {
-            for z in inputs {
-                if x + y + z == 2020 {
-                    return x * y * z;
-                }
-            }
-        }
-    }
-    return -1;
-}
-
 fn main() {
-    let inputs = parse_integers();
-    println!("A: {}", part_a(&inputs));
-    println!("B: {}", part_b(&inputs));
+    // Inputs too large to hold in memory can be streamed from a file with `--stream <path>`.
+    if let Some(path) = aoc::stream::path_argument() {
+        aoc::log::init();
+        let mut day = day_01::Day01::default();
+        aoc::unwrap_or_exit(aoc::configure(&mut day));
+        let answer = day_01::solve_streaming(&path, day.target as isize);
+        println!("A: {}", aoc::unwrap_or_exit(answer));
+        return;
+    }
+    aoc::main(day_01::Day01::default());
 }
@@ -0,0 +1,94 @@
+use aoc::record::{parse_records, Fields, Format, FromInput};
+use aoc::{Error, Solution};
+use policy::{count_valid, Count, Positions};
+
+pub mod policy;
+pub mod report;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<DatabaseEntry>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 2;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<DatabaseEntry>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, entries: &Vec<DatabaseEntry>) -> Result<usize, Error> {
+        return Ok(part_a(entries));
+    }
+
+    fn part_b(&self, entries: &Vec<DatabaseEntry>) -> Result<usize, Error> {
+        return Ok(part_b(entries));
+    }
+}
+
+/// A password with the token and the two numbers of the policy it was set under.
+pub struct DatabaseEntry {
+    line: usize,
+    x: usize,
+    y: usize,
+    token: String,
+    password: String,
+}
+
+impl DatabaseEntry {
+    /// The line of the input the entry is on.
+    pub fn line(&self) -> usize {
+        return self.line;
+    }
+
+    /// The numbers before the token, a range for `Count` and positions for `Positions`.
+    pub fn positions(&self) -> (usize, usize) {
+        return (self.x, self.y);
+    }
+
+    /// The character, or characters, the policy is about.
+    pub fn token(&self) -> &str {
+        return &self.token;
+    }
+
+    pub fn password(&self) -> &str {
+        return &self.password;
+    }
+}
+
+impl FromInput for DatabaseEntry {
+    fn format() -> Format {
+        return Format::new("{x:int}-{y:int} {token}: {password}")
+            .expected("an entry like \"1-3 a: abcde\"");
+    }
+
+    fn from_fields(fields: &Fields) -> Result<DatabaseEntry, Error> {
+        // An empty token would occur at every position, and a blank one is most likely missing.
+        let token: String = fields.get("token")?;
+        if token.trim().is_empty() {
+            return Err(fields.error("token", "a token of at least one character"));
+        }
+        return Ok(DatabaseEntry {
+            line: fields.line(),
+            x: fields.get("x")?,
+            y: fields.get("y")?,
+            token,
+            password: fields.get("password")?,
+        });
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<DatabaseEntry>, Error> {
+    return parse_records(input);
+}
+
+fn part_a(entries: &[DatabaseEntry]) -> usize {
+    return count_valid(entries, &Count);
+}
+
+fn part_b(entries: &[DatabaseEntry]) -> usize {
+    return count_valid(entries, &Positions);
+}
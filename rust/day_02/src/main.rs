@@ -1,66 +1,22 @@
-use regex::Regex;
-use std::io::{self, Read};
+use aoc::Solution;
+use day_02::report::{puzzle_policies, Report};
+use std::env;
 
-struct DatabaseEntry {
-    x: usize,
-    y: usize,
-    character: String,
-    password: String,
-}
-
-fn parse_inputs() -> Vec<DatabaseEntry> {
-    let mut buffer = String::new();
-    let mut stdin = io::stdin();
-    match stdin.read_to_string(&mut buffer) {
-        Ok(n) => println!("Parsed {}", n),
-        Err(_) => panic!("Could not read from stdin"),
-    };
-
-    let mut vec = Vec::new();
-
-    let pattern = Regex::new(r"(\d+)-(\d+) (.): (.+)").unwrap();
-    for cap in pattern.captures_iter(&buffer) {
-        let entry = DatabaseEntry {
-            x: cap[1].parse().unwrap(),
-            y: cap[2].parse().unwrap(),
-            character: cap[3].to_string(),
-            password: cap[4].to_string(),
-        };
-        vec.push(entry);
-    }
-
-    return vec;
-}
-
-fn part_a(entries: &Vec<DatabaseEntry>) -> i32 {
-    let mut counter = 0;
-
-    for entry in entries {
-        let char_count: usize = entry.password.matches(&entry.character).count();
-        if entry.x <= char_count && char_count <= entry.y {
-            counter += 1;
-        }
-    }
-
-    return counter;
-}
-
-fn part_b(entries: &Vec<DatabaseEntry>) -> i32 {
-    let mut counter = 0;
-
-    for entry in entries {
-        let first: String = entry.password.chars().nth(entry.x - 1).unwrap().to_string();
-        let second: String = entry.password.chars().nth(entry.y - 1).unwrap().to_string();
-        if (first == entry.character || second == entry.character) && !(first == second) {
-            counter += 1;
+fn main() {
+    // Audit every entry with `--report`, or as a spreadsheet with `--csv`. The totals per policy
+    // and per token are spreadsheets of their own, with `--csv-policies` and `--csv-tokens`.
+    let modes = ["--report", "--csv", "--csv-policies", "--csv-tokens"];
+    if let Some(mode) = env::args().find(|a| modes.contains(&a.as_str())) {
+        aoc::log::init();
+        let entries = aoc::unwrap_or_exit(aoc::read_stdin().and_then(|i| day_02::Day02.parse(&i)));
+        let report = Report::new(&entries, &puzzle_policies());
+        match mode.as_str() {
+            "--csv" => print!("{}", report.to_csv()),
+            "--csv-policies" => print!("{}", report.policies_csv()),
+            "--csv-tokens" => print!("{}", report.tokens_csv()),
+            _ => print!("{}", report),
         }
+        return;
     }
-
-    return counter;
-}
-
-fn main() {
-    let inputs = parse_inputs();
-    println!("A: {}", part_a(&inputs));
-    println!("B: {}", part_b(&inputs));
+    aoc::main(day_02::Day02);
 }
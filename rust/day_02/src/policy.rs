@@ -0,0 +1,305 @@
+//! Password policies that check database entries and explain their verdicts.
+//!
+//! [`Count`] and [`Positions`] are the policies from the puzzle, [`AllOf`], [`AnyOf`] and [`Not`]
+//! combine other policies, and [`Matches`] checks the password against a regex. Tokens may be
+//! longer than a single character, and occurrences of a token may overlap: `aa` occurs twice in
+//! `aaa`, at positions 1 and 2. Positions past either end of a password never hold a token.
+
+use crate::DatabaseEntry;
+use regex::Regex;
+
+/// Whether an entry passes a policy, and why.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Verdict {
+    pub passed: bool,
+    pub reason: String,
+}
+
+impl Verdict {
+    fn new(passed: bool, reason: String) -> Verdict {
+        return Verdict { passed, reason };
+    }
+}
+
+pub trait Policy: Send + Sync {
+    /// A short description, like "count" or "not(positions)".
+    fn name(&self) -> String;
+
+    fn check(&self, entry: &DatabaseEntry) -> Verdict;
+}
+
+/// The token occurs from `x` up to `y` times in the password, the policy of part A.
+pub struct Count;
+
+impl Policy for Count {
+    fn name(&self) -> String {
+        return "count".to_string();
+    }
+
+    fn check(&self, entry: &DatabaseEntry) -> Verdict {
+        let (low, high) = entry.positions();
+        let count = occurrences(entry.password(), entry.token());
+        return Verdict::new(
+            low <= count && count <= high,
+            format!(
+                "{:?} occurs {} times, {} to {} allowed",
+                entry.token(),
+                count,
+                low,
+                high
+            ),
+        );
+    }
+}
+
+/// The token starts at exactly one of the positions `x` and `y`, counting from 1, the policy of
+/// part B.
+pub struct Positions;
+
+impl Positions {
+    fn describe(entry: &DatabaseEntry, position: usize) -> (bool, String) {
+        let chars: Vec<char> = entry.password().chars().collect();
+        let token: Vec<char> = entry.token().chars().collect();
+        let fits = match position.checked_add(token.len()) {
+            Some(end) => position > 0 && end <= chars.len() + 1,
+            None => false,
+        };
+        if !fits {
+            return (false, format!("{} is out of range", position));
+        }
+        let found: String = chars[position - 1..position - 1 + token.len()]
+            .iter()
+            .collect();
+        return (
+            found == entry.token(),
+            format!("{} holds {:?}", position, found),
+        );
+    }
+}
+
+impl Policy for Positions {
+    fn name(&self) -> String {
+        return "positions".to_string();
+    }
+
+    fn check(&self, entry: &DatabaseEntry) -> Verdict {
+        let (x, y) = entry.positions();
+        let (first, first_reason) = Positions::describe(entry, x);
+        let (second, second_reason) = Positions::describe(entry, y);
+        let held = match (first, second) {
+            (true, true) => "both hold",
+            (false, false) => "neither holds",
+            _ => "one holds",
+        };
+        return Verdict::new(
+            first != second,
+            format!(
+                "{} {:?}: position {}, position {}",
+                held,
+                entry.token(),
+                first_reason,
+                second_reason
+            ),
+        );
+    }
+}
+
+/// Every one of the policies passes, the reason lists the ones that failed.
+pub struct AllOf(pub Vec<Box<dyn Policy>>);
+
+impl Policy for AllOf {
+    fn name(&self) -> String {
+        return format!("all({})", names(&self.0));
+    }
+
+    fn check(&self, entry: &DatabaseEntry) -> Verdict {
+        let failed = explain(&self.0, entry, false);
+        return match failed.is_empty() {
+            true => Verdict::new(true, format!("all {} passed", self.0.len())),
+            false => Verdict::new(false, failed.join("; ")),
+        };
+    }
+}
+
+/// At least one of the policies passes, the reason lists the ones that did.
+pub struct AnyOf(pub Vec<Box<dyn Policy>>);
+
+impl Policy for AnyOf {
+    fn name(&self) -> String {
+        return format!("any({})", names(&self.0));
+    }
+
+    fn check(&self, entry: &DatabaseEntry) -> Verdict {
+        let passed = explain(&self.0, entry, true);
+        return match passed.is_empty() {
+            true => Verdict::new(false, format!("none of {} passed", self.0.len())),
+            false => Verdict::new(true, passed.join("; ")),
+        };
+    }
+}
+
+/// The policy fails.
+pub struct Not(pub Box<dyn Policy>);
+
+impl Policy for Not {
+    fn name(&self) -> String {
+        return format!("not({})", self.0.name());
+    }
+
+    fn check(&self, entry: &DatabaseEntry) -> Verdict {
+        let verdict = self.0.check(entry);
+        return Verdict::new(!verdict.passed, verdict.reason);
+    }
+}
+
+/// The password matches a regex somewhere.
+pub struct Matches(pub Regex);
+
+impl Policy for Matches {
+    fn name(&self) -> String {
+        return format!("matches(/{}/)", self.0.as_str());
+    }
+
+    fn check(&self, entry: &DatabaseEntry) -> Verdict {
+        return match self.0.find(entry.password()) {
+            Some(m) => Verdict::new(true, format!("matches at {}", m.start() + 1)),
+            None => Verdict::new(false, format!("does not match /{}/", self.0.as_str())),
+        };
+    }
+}
+
+/// The number of positions the token starts at, counting overlapping occurrences.
+fn occurrences(password: &str, token: &str) -> usize {
+    return password
+        .char_indices()
+        .filter(|(i, _)| password[*i..].starts_with(token))
+        .count();
+}
+
+fn names(policies: &[Box<dyn Policy>]) -> String {
+    return policies
+        .iter()
+        .map(|p| p.name())
+        .collect::<Vec<String>>()
+        .join(", ");
+}
+
+/// The reasons of the policies with the given outcome, prefixed with their names.
+fn explain(policies: &[Box<dyn Policy>], entry: &DatabaseEntry, passed: bool) -> Vec<String> {
+    return policies
+        .iter()
+        .map(|p| (p.name(), p.check(entry)))
+        .filter(|(_, v)| v.passed == passed)
+        .map(|(name, v)| format!("{}: {}", name, v.reason))
+        .collect();
+}
+
+/// The number of entries that pass the policy.
+pub fn count_valid(entries: &[DatabaseEntry], policy: &dyn Policy) -> usize {
+    return entries.iter().filter(|e| policy.check(e).passed).count();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_inputs;
+
+    fn entry(line: &str) -> DatabaseEntry {
+        return parse_inputs(line).unwrap().remove(0);
+    }
+
+    #[test]
+    fn verdicts() {
+        let e = entry("1-3 b: cdefg");
+        assert_eq!(
+            Count.check(&e),
+            Verdict::new(false, "\"b\" occurs 0 times, 1 to 3 allowed".to_string())
+        );
+
+        let e = entry("2-9 c: ccccccccc");
+        assert_eq!(
+            Positions.check(&e).reason,
+            "both hold \"c\": position 2 holds \"c\", position 9 holds \"c\""
+        );
+        assert!(!Positions.check(&e).passed);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let e = entry("3-7 a: xya");
+        let verdict = Positions.check(&e);
+        assert!(verdict.passed);
+        assert_eq!(
+            verdict.reason,
+            "one holds \"a\": position 3 holds \"a\", position 7 is out of range"
+        );
+        assert!(!Positions.check(&entry("0-9 a: a")).passed);
+    }
+
+    #[test]
+    fn tokens() {
+        let e = entry("2-3 ab: abxabab");
+        assert!(Count.check(&e).passed);
+        assert!(!Positions.check(&e).passed);
+        assert!(Positions.check(&entry("4-5 ab: abxabab")).passed);
+        assert!(!Positions.check(&entry("5-7 ab: abxabab")).passed);
+    }
+
+    #[test]
+    fn overlapping() {
+        let e = entry("2-2 aa: aaa");
+        assert_eq!(
+            Count.check(&e).reason,
+            "\"aa\" occurs 2 times, 2 to 2 allowed"
+        );
+        assert!(Count.check(&e).passed);
+        assert_eq!(
+            Positions.check(&entry("1-2 aa: aaa")).reason,
+            "both hold \"aa\": position 1 holds \"aa\", position 2 holds \"aa\""
+        );
+    }
+
+    #[test]
+    fn huge_positions() {
+        let verdict = Positions.check(&entry("18446744073709551615-2 a: abc"));
+        assert!(!verdict.passed);
+        assert_eq!(
+            verdict.reason,
+            "neither holds \"a\": position 18446744073709551615 is out of range, \
+             position 2 holds \"b\""
+        );
+    }
+
+    #[test]
+    fn empty_token() {
+        assert_eq!(
+            parse_inputs("1-3 a: abc\n1-3  : abc")
+                .err()
+                .unwrap()
+                .to_string(),
+            "line 2, column 5: unexpected \" \", expected a token of at least one character"
+        );
+    }
+
+    #[test]
+    fn combined() {
+        let policy = AllOf(vec![
+            Box::new(Count),
+            Box::new(Not(Box::new(Matches(Regex::new("^c").unwrap())))),
+        ]);
+        assert_eq!(policy.name(), "all(count, not(matches(/^c/)))");
+        assert!(policy.check(&entry("1-3 a: abcde")).passed);
+        assert_eq!(
+            policy.check(&entry("2-9 c: ccccccccc")).reason,
+            "not(matches(/^c/)): matches at 1"
+        );
+
+        let policy = AnyOf(vec![Box::new(Count), Box::new(Positions)]);
+        assert!(policy.check(&entry("1-3 a: abcde")).passed);
+        assert!(policy.check(&entry("2-9 c: ccccccccc")).passed);
+        assert_eq!(
+            policy.check(&entry("1-3 b: cdefg")).reason,
+            "none of 2 passed"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! An audit of the password database, listing the verdicts of every entry with totals.
+
+use crate::policy::{Count, Policy, Positions, Verdict};
+use crate::DatabaseEntry;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The policies of both parts of the puzzle.
+pub fn puzzle_policies() -> Vec<Box<dyn Policy>> {
+    return vec![Box::new(Count), Box::new(Positions)];
+}
+
+/// An entry with the verdict of every policy, in the order of the policies.
+#[derive(Clone, Debug)]
+pub struct Row {
+    pub line: usize,
+    pub positions: (usize, usize),
+    pub token: String,
+    pub password: String,
+    pub verdicts: Vec<Verdict>,
+}
+
+/// How many entries with a token pass every policy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenStats {
+    pub token: String,
+    pub entries: usize,
+    pub passed: Vec<usize>,
+}
+
+pub struct Report {
+    pub policies: Vec<String>,
+    pub rows: Vec<Row>,
+}
+
+impl Report {
+    pub fn new(entries: &[DatabaseEntry], policies: &[Box<dyn Policy>]) -> Report {
+        let rows = entries
+            .iter()
+            .map(|e| Row {
+                line: e.line(),
+                positions: e.positions(),
+                token: e.token().to_string(),
+                password: e.password().to_string(),
+                verdicts: policies.iter().map(|p| p.check(e)).collect(),
+            })
+            .collect();
+        return Report {
+            policies: policies.iter().map(|p| p.name()).collect(),
+            rows,
+        };
+    }
+
+    /// The number of entries that pass each policy.
+    pub fn passed(&self) -> Vec<usize> {
+        return (0..self.policies.len())
+            .map(|i| self.rows.iter().filter(|r| r.verdicts[i].passed).count())
+            .collect();
+    }
+
+    /// The totals per token, in order of the tokens.
+    pub fn tokens(&self) -> Vec<TokenStats> {
+        let mut tokens: BTreeMap<&str, TokenStats> = BTreeMap::new();
+        for row in &self.rows {
+            let stats = tokens.entry(&row.token).or_insert_with(|| TokenStats {
+                token: row.token.clone(),
+                entries: 0,
+                passed: vec![0; self.policies.len()],
+            });
+            stats.entries += 1;
+            for (passed, verdict) in stats.passed.iter_mut().zip(&row.verdicts) {
+                *passed += verdict.passed as usize;
+            }
+        }
+        return tokens.into_values().collect();
+    }
+
+    /// A row per entry, with a pass or fail and a reason column for each policy.
+    pub fn to_csv(&self) -> String {
+        let mut header = vec![
+            "line".to_string(),
+            "x".to_string(),
+            "y".to_string(),
+            "token".to_string(),
+            "password".to_string(),
+        ];
+        for policy in &self.policies {
+            header.push(policy.clone());
+            header.push(format!("{} reason", policy));
+        }
+
+        let mut csv = csv_line(&header);
+        for row in &self.rows {
+            let mut fields = vec![
+                row.line.to_string(),
+                row.positions.0.to_string(),
+                row.positions.1.to_string(),
+                row.token.clone(),
+                row.password.clone(),
+            ];
+            for verdict in &row.verdicts {
+                fields.push(outcome(verdict).to_string());
+                fields.push(verdict.reason.clone());
+            }
+            csv.push_str(&csv_line(&fields));
+        }
+        return csv;
+    }
+
+    /// A row per policy, with the number of entries that pass and fail it.
+    pub fn policies_csv(&self) -> String {
+        let header = ["policy", "entries", "passed", "failed"].map(String::from);
+        let mut csv = csv_line(&header);
+        for (policy, passed) in self.policies.iter().zip(self.passed()) {
+            csv.push_str(&csv_line(&[
+                policy.clone(),
+                self.rows.len().to_string(),
+                passed.to_string(),
+                (self.rows.len() - passed).to_string(),
+            ]));
+        }
+        return csv;
+    }
+
+    /// A row per token, with the number of its entries and how many pass each policy.
+    pub fn tokens_csv(&self) -> String {
+        let mut header = vec!["token".to_string(), "entries".to_string()];
+        for policy in &self.policies {
+            header.push(format!("{} passed", policy));
+        }
+
+        let mut csv = csv_line(&header);
+        for stats in self.tokens() {
+            let mut fields = vec![stats.token.clone(), stats.entries.to_string()];
+            fields.extend(stats.passed.iter().map(|p| p.to_string()));
+            csv.push_str(&csv_line(&fields));
+        }
+        return csv;
+    }
+}
+
+fn outcome(verdict: &Verdict) -> &'static str {
+    return if verdict.passed { "pass" } else { "fail" };
+}
+
+/// Quote the fields that need it, doubling the quotes inside them.
+fn csv_line(fields: &[String]) -> String {
+    let quoted: Vec<String> = fields
+        .iter()
+        .map(|f| {
+            if f.contains([',', '"', '\n', '\r']) {
+                return format!("\"{}\"", f.replace('"', "\"\""));
+            }
+            return f.clone();
+        })
+        .collect();
+    return format!("{}\n", quoted.join(","));
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(
+                f,
+                "line {}: {}-{} {}: {}",
+                row.line, row.positions.0, row.positions.1, row.token, row.password
+            )?;
+            for (policy, verdict) in self.policies.iter().zip(&row.verdicts) {
+                writeln!(
+                    f,
+                    "    {}: {}, {}",
+                    policy,
+                    outcome(verdict),
+                    verdict.reason
+                )?;
+            }
+        }
+
+        writeln!(f)?;
+        for (policy, passed) in self.policies.iter().zip(self.passed()) {
+            writeln!(f, "{}: {} of {} passed", policy, passed, self.rows.len())?;
+        }
+
+        writeln!(f)?;
+        for stats in self.tokens() {
+            let passed: Vec<String> = self
+                .policies
+                .iter()
+                .zip(&stats.passed)
+                .map(|(policy, passed)| format!("{} {}", policy, passed))
+                .collect();
+            writeln!(
+                f,
+                "{:?}: {} entries, passed {}",
+                stats.token,
+                stats.entries,
+                passed.join(", ")
+            )?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_inputs;
+
+    fn example() -> Report {
+        let entries =
+            parse_inputs("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n1-2 a: b,\"a\"").unwrap();
+        return Report::new(&entries, &puzzle_policies());
+    }
+
+    #[test]
+    fn stats() {
+        let report = example();
+        assert_eq!(report.policies, vec!["count", "positions"]);
+        assert_eq!(report.passed(), vec![3, 1]);
+        assert_eq!(
+            report.tokens()[0],
+            TokenStats {
+                token: "a".to_string(),
+                entries: 2,
+                passed: vec![2, 1],
+            }
+        );
+        assert_eq!(report.rows[2].line, 3);
+    }
+
+    #[test]
+    fn csv() {
+        let csv = example().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "line,x,y,token,password,count,count reason,positions,positions reason"
+        );
+        assert_eq!(
+            lines[2],
+            "2,1,3,b,cdefg,fail,\"\"\"b\"\" occurs 0 times, 1 to 3 allowed\",\
+             fail,\"neither holds \"\"b\"\": position 1 holds \"\"c\"\", position 3 holds \"\"e\"\"\""
+        );
+        assert!(lines[4].starts_with("4,1,2,a,\"b,\"\"a\"\"\",pass,"));
+    }
+
+    #[test]
+    fn stats_csv() {
+        let report = example();
+        assert_eq!(
+            report.policies_csv(),
+            "policy,entries,passed,failed\ncount,4,3,1\npositions,4,1,3\n"
+        );
+        assert_eq!(
+            report.tokens_csv(),
+            "token,entries,count passed,positions passed\na,2,2,1\nb,1,0,0\nc,1,1,0\n"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use aoc::grid::Grid;
+use aoc::math::gcd;
+use aoc::params::{parse_param, unknown_param};
+use aoc::{Error, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+/// Count the trees hit going down the `slope` for part A, and multiply them over the `slopes` for
+/// part B.
+pub struct Day03 {
+    pub slope: Slope,
+    pub slopes: Vec<Slope>,
+}
+
+impl Default for Day03 {
+    fn default() -> Day03 {
+        let slope = |right, down| Slope::new(right, down).unwrap();
+        return Day03 {
+            slope: slope(3, 1),
+            slopes: vec![
+                slope(1, 1),
+                slope(3, 1),
+                slope(5, 1),
+                slope(7, 1),
+                slope(1, 2),
+            ],
+        };
+    }
+}
+
+impl Solution for Day03 {
+    type Input = Grid<char>;
+    type AnswerA = i128;
+    type AnswerB = i128;
+
+    fn day(&self) -> u8 {
+        return 3;
+    }
+
+    fn parse(&self, input: &str) -> Result<Grid<char>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, map: &Grid<char>) -> Result<i128, Error> {
+        return Ok(count_trees(map, self.slope) as i128);
+    }
+
+    fn part_b(&self, map: &Grid<char>) -> Result<i128, Error> {
+        return Ok(self
+            .slopes
+            .iter()
+            .map(|s| count_trees(map, *s) as i128)
+            .product());
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let slopes: Vec<String> = self.slopes.iter().map(|s| s.to_string()).collect();
+        return vec![
+            ("slope", self.slope.to_string()),
+            ("slopes", slopes.join(",")),
+        ];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "slope" => self.slope = slope_param(name, value)?,
+            "slopes" => {
+                self.slopes = value
+                    .split(',')
+                    .map(|s| slope_param(name, s.trim()))
+                    .collect::<Result<Vec<Slope>, Error>>()?
+            }
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+const SLOPE: &str = "a slope like 3, -1, 1/2 or 0.5 columns per row";
+
+/// Parse a slope parameter, keeping the reason when a well formed slope does not go down.
+fn slope_param(name: &str, value: &str) -> Result<Slope, Error> {
+    return match value.parse() {
+        Err(Error::Param(reason)) => {
+            Err(Error::Param(format!("{} = {:?}, {}", name, value, reason)))
+        }
+        Err(_) => parse_param(name, value, SLOPE),
+        slope => slope,
+    };
+}
+
+/// The columns moved right per row down, kept as a reduced fraction.
+///
+/// A slope of 1/2 moves a column every two rows and skips the rows in between, negative slopes
+/// move left. The map repeats in both directions, so any slope stays on it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Slope {
+    right: i64,
+    down: i64,
+}
+
+impl Slope {
+    /// Fails when `down` is not positive, as the toboggan would never reach the bottom.
+    pub fn new(right: i64, down: i64) -> Result<Slope, Error> {
+        if down <= 0 {
+            return Err(Error::Param(format!(
+                "a slope of {} per {} rows never reaches the bottom",
+                right, down
+            )));
+        }
+        let divisor = gcd(right as i128, down as i128) as i64;
+        return Ok(Slope {
+            right: right / divisor,
+            down: down / divisor,
+        });
+    }
+
+    pub fn right(&self) -> i64 {
+        return self.right;
+    }
+
+    pub fn down(&self) -> i64 {
+        return self.down;
+    }
+}
+
+impl FromStr for Slope {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Slope, Self::Err> {
+        let invalid = || Error::parse(1, 1, input, SLOPE);
+        let (right, down): (i64, i64) = match (input.split_once('/'), input.split_once('.')) {
+            (Some((right, down)), _) => (
+                right.parse().map_err(|_| invalid())?,
+                down.parse().map_err(|_| invalid())?,
+            ),
+            (None, Some((whole, fraction))) => {
+                if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                let down = 10i64
+                    .checked_pow(fraction.len() as u32)
+                    .ok_or_else(invalid)?;
+                (
+                    format!("{}{}", whole, fraction)
+                        .parse()
+                        .map_err(|_| invalid())?,
+                    down,
+                )
+            }
+            (None, None) => (input.parse().map_err(|_| invalid())?, 1),
+        };
+        return Slope::new(right, down);
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.down == 1 {
+            return write!(f, "{}", self.right);
+        }
+        return write!(f, "{}/{}", self.right, self.down);
+    }
+}
+
+const OPEN: char = '.';
+const TREE: char = '#';
+
+fn parse_inputs(input: &str) -> Result<Grid<char>, Error> {
+    let map = Grid::parse_with(input, "'.' or '#'", |c| match c {
+        OPEN | TREE => Some(c),
+        _ => None,
+    })?;
+
+    if map.width() == 0 {
+        return Err(Error::parse(1, 1, "", "a row of '.' and '#'"));
+    }
+
+    return Ok(map);
+}
+
+/// The trees hit going down from the top left corner.
+pub fn count_trees(map: &Grid<char>, slope: Slope) -> usize {
+    // The map repeats to the right, and to the left for negative slopes, so a step only matters
+    // within one width of the map. That also keeps huge slopes from overflowing.
+    let width = map.width().max(1);
+    let step = slope.right.rem_euclid(width as i64) as usize;
+    let mut x = 0;
+    let mut trees = 0;
+    for y in (0..map.height()).step_by(slope.down as usize) {
+        if *map.get_wrapping(x as isize, y as isize) == TREE {
+            trees += 1;
+        }
+        x = (x + step) % width;
+    }
+    return trees;
+}
+
+/// Whether [`best_slope`] looks for the fewest or the most trees.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Goal {
+    Fewest,
+    Most,
+}
+
+/// Every distinct slope of at most `max_right` columns either way per at most `max_down` rows, in
+/// order of the rows and then the columns.
+pub fn slopes_within(max_right: i64, max_down: i64) -> Vec<Slope> {
+    let mut slopes = Vec::new();
+    for down in 1..=max_down {
+        for right in -max_right..=max_right {
+            // Unreduced slopes are already in the list as a reduced one.
+            if gcd(right as i128, down as i128) == 1 {
+                slopes.push(Slope { right, down });
+            }
+        }
+    }
+    return slopes;
+}
+
+/// The slope hitting the fewest or most trees with the number it hits, the first of the
+/// candidates wins a tie. `None` without candidates.
+pub fn best_slope(map: &Grid<char>, candidates: &[Slope], goal: Goal) -> Option<(Slope, usize)> {
+    let mut best: Option<(Slope, usize)> = None;
+    for slope in candidates {
+        let trees = count_trees(map, *slope);
+        let better = match (best, goal) {
+            (None, _) => true,
+            (Some((_, b)), Goal::Fewest) => trees < b,
+            (Some((_, b)), Goal::Most) => trees > b,
+        };
+        if better {
+            best = Some((*slope, trees));
+        }
+    }
+    return best;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    fn slope(right: i64, down: i64) -> Slope {
+        return Slope::new(right, down).unwrap();
+    }
+
+    #[test]
+    fn slopes() {
+        assert_eq!("3".parse::<Slope>().unwrap(), slope(3, 1));
+        assert_eq!("-2/4".parse::<Slope>().unwrap(), slope(-1, 2));
+        assert_eq!("0.5".parse::<Slope>().unwrap(), slope(1, 2));
+        assert_eq!("-1.25".parse::<Slope>().unwrap(), slope(-5, 4));
+        assert_eq!(slope(-5, 4).to_string(), "-5/4");
+        for invalid in ["", "1/0", "1/-2", "x", "1.", ".5.5", "1/2/3"] {
+            assert!(invalid.parse::<Slope>().is_err(), "{:?}", invalid);
+        }
+        assert!(Slope::new(1, 0).is_err());
+    }
+
+    #[test]
+    fn example() {
+        let map = parse_inputs(EXAMPLE).unwrap();
+        let mut day = Day03::default();
+        assert_eq!(day.part_b(&map).unwrap(), 336);
+
+        // Going left mirrors the map, which hits trees at other places.
+        assert_eq!(count_trees(&map, slope(3, 1)), 7);
+        assert_eq!(count_trees(&map, slope(-3, 1)), 3);
+        assert_eq!(count_trees(&map, slope(1, 2)), 2);
+
+        day.set_param("slopes", "3, 0.5").unwrap();
+        assert_eq!(day.part_b(&map).unwrap(), 14);
+        assert_eq!(
+            day.set_param("slope", "1/0").unwrap_err().to_string(),
+            "bad parameter: slope = \"1/0\", a slope of 1 per 0 rows never reaches the bottom"
+        );
+        assert_eq!(
+            day.set_param("slope", "x").unwrap_err().to_string(),
+            format!("bad parameter: slope = \"x\", expected {}", SLOPE)
+        );
+    }
+
+    #[test]
+    fn huge_slopes() {
+        let map = parse_inputs(EXAMPLE).unwrap();
+        let mut day = Day03::default();
+        day.set_param("slope", "9223372036854775807").unwrap();
+        assert_eq!(
+            day.part_a(&map).unwrap(),
+            count_trees(&map, slope(i64::MAX % 11, 1)) as i128
+        );
+        assert_eq!(
+            count_trees(&map, slope(i64::MIN, 1)),
+            count_trees(&map, slope(i64::MIN.rem_euclid(11), 1))
+        );
+    }
+
+    #[test]
+    fn search() {
+        let map = parse_inputs(EXAMPLE).unwrap();
+        let candidates = slopes_within(3, 2);
+        assert_eq!(candidates.len(), 7 + 4);
+        assert_eq!(
+            best_slope(&map, &candidates, Goal::Most),
+            Some((slope(3, 1), 7))
+        );
+        let (slope, trees) = best_slope(&map, &candidates, Goal::Fewest).unwrap();
+        assert_eq!(trees, count_trees(&map, slope));
+        assert!(candidates.iter().all(|s| count_trees(&map, *s) >= trees));
+        assert_eq!(best_slope(&map, &[], Goal::Fewest), None);
+    }
+}
@@ -0,0 +1,144 @@
+use aoc::{parse_blocks_from_str, Error, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<HashMap<String, String>>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 4;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<HashMap<String, String>>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, passports: &Vec<HashMap<String, String>>) -> Result<usize, Error> {
+        return Ok(count_valid(passports));
+    }
+
+    fn part_b(&self, passports: &Vec<HashMap<String, String>>) -> Result<usize, Error> {
+        return Ok(count_valid_strict(passports));
+    }
+}
+
+fn parse_passport(raw_passport: &str) -> Result<HashMap<String, String>, Error> {
+    let mut passport = HashMap::new();
+
+    for (i, raw_line) in raw_passport.split('\n').enumerate() {
+        let mut column = 1;
+        for raw_entry in raw_line.split(' ') {
+            if !raw_entry.is_empty() {
+                match raw_entry.split_once(':') {
+                    Some((key, value)) => {
+                        passport.insert(key.to_string(), value.to_string());
+                    }
+                    None => return Err(Error::parse(i + 1, column, raw_entry, "a key:value pair")),
+                }
+            }
+            column += raw_entry.chars().count() + 1;
+        }
+    }
+
+    passport.insert("cid".to_string(), "foo".to_string());
+    return Ok(passport);
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<HashMap<String, String>>, Error> {
+    let mut vec = Vec::new();
+
+    // Iterate over all passports, keeping track of the line they start on.
+    let mut line = 0;
+    for raw_passport in parse_blocks_from_str(input)? {
+        vec.push(parse_passport(&raw_passport).map_err(|e| e.offset(line))?);
+        line += raw_passport.split('\n').count() + 1;
+    }
+
+    return Ok(vec);
+}
+
+fn count_valid(passports: &[HashMap<String, String>]) -> usize {
+    let mut counter = 0;
+
+    for passport in passports {
+        if passport.len() == 8 {
+            counter += 1;
+        }
+    }
+
+    return counter;
+}
+
+fn count_valid_strict(passports: &[HashMap<String, String>]) -> usize {
+    let mut counter = 0;
+
+    let hcl = Regex::new(r"^#[0-9a-f]{6}$").unwrap();
+    let ecl = Regex::new(r"amb|blu|brn|gry|grn|hzl|oth").unwrap();
+    let pid = Regex::new(r"^[0-9]{9}$").unwrap();
+
+    for passport in passports {
+        if passport.len() < 8 {
+            // There are keys missing, no point in validating.
+            continue;
+        }
+
+        // byr (Birth Year) - four digits; at least 1920 and at most 2002.
+        match passport["byr"].parse::<usize>() {
+            Ok(1920..=2002) => {}
+            _ => continue,
+        }
+
+        // iyr (Issue Year) - four digits; at least 2010 and at most 2020.
+        match passport["iyr"].parse::<usize>() {
+            Ok(2010..=2020) => {}
+            _ => continue,
+        }
+
+        // eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
+        match passport["eyr"].parse::<usize>() {
+            Ok(2020..=2030) => {}
+            _ => continue,
+        }
+
+        // hgt (Height) - a number followed by either cm or in:
+        // If cm, the number must be at least 150 and at most 193.
+        if passport["hgt"].contains("cm") {
+            match passport["hgt"].replace("cm", "").parse::<usize>() {
+                Ok(150..=193) => {}
+                _ => continue,
+            }
+        }
+        // If in, the number must be at least 59 and at most 76.
+        else if passport["hgt"].contains("in") {
+            match passport["hgt"].replace("in", "").parse::<usize>() {
+                Ok(59..=76) => {}
+                _ => continue,
+            }
+        } else {
+            continue;
+        }
+
+        // hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
+        if !hcl.is_match(&passport["hcl"]) {
+            continue;
+        }
+
+        // ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
+        if !ecl.is_match(&passport["ecl"]) {
+            continue;
+        }
+
+        // pid (Passport ID) - a nine-digit number, including leading zeroes.
+        if !pid.is_match(&passport["pid"]) {
+            continue;
+        }
+
+        counter += 1;
+    }
+
+    return counter;
+}
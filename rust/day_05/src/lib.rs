@@ -0,0 +1,78 @@
+use aoc::{parse_lines_from_str, Error, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<usize>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 5;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, Error> {
+        return Ok(parse_inputs(input)?.iter().map(|x| parse_bin(x)).collect());
+    }
+
+    fn part_a(&self, seats: &Vec<usize>) -> Result<usize, Error> {
+        return seats
+            .iter()
+            .max()
+            .cloned()
+            .ok_or_else(|| Error::NoSolution("there are no boarding passes".to_string()));
+    }
+
+    fn part_b(&self, seats: &Vec<usize>) -> Result<usize, Error> {
+        return find_missing_seat(seats);
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<String>, Error> {
+    let mut vec = Vec::new();
+
+    let pattern = Regex::new(r"^([BF]{7}[LR]{3})$").unwrap();
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        match pattern.captures(line) {
+            Some(cap) => vec.push(cap[1].to_string()),
+            None => {
+                return Err(Error::parse(
+                    i + 1,
+                    1,
+                    line,
+                    "a boarding pass like \"FBFBBFFRLR\"",
+                ))
+            }
+        }
+    }
+
+    return Ok(vec);
+}
+
+fn parse_bin(input: &str) -> usize {
+    let bin = input
+        .replace("F", "0")
+        .replace("B", "1")
+        .replace("L", "0")
+        .replace("R", "1");
+    let intval = usize::from_str_radix(&bin, 2).unwrap();
+    return intval;
+}
+
+/// The free seat between the lowest and highest taken ones.
+fn find_missing_seat(seats: &[usize]) -> Result<usize, Error> {
+    let occupied: HashSet<usize> = seats.iter().cloned().collect();
+    let (min, max) = match (seats.iter().min(), seats.iter().max()) {
+        (Some(min), Some(max)) => (*min, *max),
+        _ => {
+            return Err(Error::NoSolution(
+                "there are no boarding passes".to_string(),
+            ))
+        }
+    };
+    return (min..max)
+        .find(|i| !occupied.contains(i))
+        .ok_or_else(|| Error::NoSolution("there is no free seat between the others".to_string()));
+}
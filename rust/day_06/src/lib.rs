@@ -0,0 +1,82 @@
+use aoc::{parse_blocks_from_str, Error, Solution};
+use std::collections::HashSet;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Vec<HashSet<char>>>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 6;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<HashSet<char>>>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &Vec<Vec<HashSet<char>>>) -> Result<usize, Error> {
+        return Ok(part_a(inputs));
+    }
+
+    fn part_b(&self, inputs: &Vec<Vec<HashSet<char>>>) -> Result<usize, Error> {
+        return Ok(part_b(inputs));
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<Vec<HashSet<char>>>, Error> {
+    let mut vec = Vec::new();
+
+    // Iterate over all groups, keeping track of the line they start on.
+    let mut line = 0;
+    for raw_group in parse_blocks_from_str(input)? {
+        let mut group = Vec::new();
+
+        for (i, raw_answers) in raw_group.split('\n').enumerate() {
+            if let Some(j) = raw_answers.chars().position(|c| !c.is_ascii_lowercase()) {
+                let text = raw_answers.chars().nth(j).unwrap().to_string();
+                return Err(Error::parse(
+                    line + i + 1,
+                    j + 1,
+                    &text,
+                    "a question from a to z",
+                ));
+            }
+            let set: HashSet<char> = raw_answers.chars().collect();
+            if !set.is_empty() {
+                group.push(set);
+            }
+        }
+        line += raw_group.split('\n').count() + 1;
+
+        if !group.is_empty() {
+            vec.push(group);
+        }
+    }
+
+    return Ok(vec);
+}
+
+fn distinct_in_group(group: &[HashSet<char>]) -> usize {
+    return group
+        .iter()
+        .fold(HashSet::new(), |acc, x| acc.union(x).copied().collect())
+        .len();
+}
+
+fn part_a(inputs: &[Vec<HashSet<char>>]) -> usize {
+    return inputs.iter().map(|g| distinct_in_group(g)).sum::<usize>();
+}
+
+fn overlap_in_group(group: &[HashSet<char>]) -> usize {
+    let mut group_iter = group.iter();
+    let overlap: HashSet<char> = group_iter.next().unwrap().clone();
+    return group_iter
+        .fold(overlap, |acc, x| acc.intersection(x).copied().collect())
+        .len();
+}
+
+fn part_b(inputs: &[Vec<HashSet<char>>]) -> usize {
+    return inputs.iter().map(|g| overlap_in_group(g)).sum::<usize>();
+}
@@ -0,0 +1,138 @@
+use aoc::params::unknown_param;
+use aoc::{parse_at, parse_lines_from_str, Error, Solution};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+/// Count the bags around and inside the `bag` color.
+pub struct Day07 {
+    pub bag: String,
+}
+
+impl Default for Day07 {
+    fn default() -> Day07 {
+        return Day07 {
+            bag: "shiny gold".to_string(),
+        };
+    }
+}
+
+impl Solution for Day07 {
+    type Input = (Containers, Contents);
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 7;
+    }
+
+    fn parse(&self, input: &str) -> Result<(Containers, Contents), Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &(Containers, Contents)) -> Result<usize, Error> {
+        return Ok(part_a(&inputs.0, &self.bag).len());
+    }
+
+    fn part_b(&self, inputs: &(Containers, Contents)) -> Result<usize, Error> {
+        return Ok(part_b(&inputs.1, &self.bag));
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return vec![("bag", self.bag.clone())];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "bag" => self.bag = value.to_string(),
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+pub type Containers = HashMap<String, HashSet<String>>;
+pub type Contents = HashMap<String, HashSet<(String, usize)>>;
+
+fn parse_inputs(input: &str) -> Result<(Containers, Contents), Error> {
+    // Map the contained versus the container.
+    let mut input_a: Containers = HashMap::new();
+    // Map the container versus its contents.
+    let mut input_b: Contents = HashMap::new();
+
+    // Define regular expressions.
+    let first = Regex::new(r"^(.+) bags contain (.+)\.$").unwrap();
+    let second = Regex::new(r"(\d+) ([\w\s]+) bags?").unwrap();
+
+    // Iterate over all rules.
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        let cap = match first.captures(line) {
+            Some(c) => c,
+            None => {
+                return Err(Error::parse(
+                    i + 1,
+                    1,
+                    line,
+                    "a rule like \"<color> bags contain <n> <color> bags.\"",
+                ))
+            }
+        };
+        let container: String = cap[1].to_string();
+        let containeds = cap.get(2).unwrap();
+
+        for bag in second.captures_iter(containeds.as_str()) {
+            let column = containeds.start() + bag.get(1).unwrap().start() + 1;
+            let amount: usize = parse_at(&bag[1], i + 1, column, "a number of bags")?;
+            let contained: String = bag[2].to_string();
+
+            match input_a.get_mut(&contained) {
+                Some(s) => {
+                    s.insert(container.clone());
+                }
+                None => {
+                    let mut s = HashSet::new();
+                    s.insert(container.clone());
+                    input_a.insert(contained.clone(), s);
+                }
+            };
+
+            match input_b.get_mut(&container) {
+                Some(s) => {
+                    s.insert((contained.clone(), amount));
+                }
+                None => {
+                    let mut s = HashSet::new();
+                    s.insert((contained.clone(), amount));
+                    input_b.insert(container.clone(), s);
+                }
+            };
+        }
+    }
+
+    return Ok((input_a, input_b));
+}
+
+fn part_a(inputs: &Containers, target: &str) -> HashSet<String> {
+    let mut possible = HashSet::new();
+
+    if let Some(s) = inputs.get(target) {
+        for container in s {
+            possible.insert(container.clone());
+            possible.extend(part_a(inputs, container));
+        }
+    };
+
+    return possible;
+}
+
+fn part_b(inputs: &Contents, container: &str) -> usize {
+    let mut count = 0;
+
+    if let Some(s) = inputs.get(container) {
+        for contained in s {
+            count += contained.1;
+            count += contained.1 * part_b(inputs, &contained.0);
+        }
+    };
+
+    return count;
+}
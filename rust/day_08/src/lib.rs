@@ -0,0 +1,170 @@
+use aoc::{parallel, parse_at, parse_lines_from_str, Error, Solution};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Operation>;
+    type AnswerA = i32;
+    type AnswerB = i32;
+
+    fn day(&self) -> u8 {
+        return 8;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Operation>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &Vec<Operation>) -> Result<i32, Error> {
+        return part_a(inputs);
+    }
+
+    fn part_b(&self, inputs: &Vec<Operation>) -> Result<i32, Error> {
+        return part_b(inputs);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operation {
+    Nop(i32),
+    Acc(i32),
+    Jmp(i32),
+}
+
+impl FromStr for Operation {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Operation, Self::Err> {
+        let (com, val) = match input.split_once(' ') {
+            Some(t) => t,
+            None => return Err(Error::parse(1, 1, input, "an operation like \"acc +1\"")),
+        };
+        let val: i32 = parse_at(val, 1, com.len() + 2, "a signed number")?;
+        match com {
+            "acc" => Ok(Operation::Acc(val)),
+            "jmp" => Ok(Operation::Jmp(val)),
+            "nop" => Ok(Operation::Nop(val)),
+            _ => Err(Error::parse(1, 1, com, "one of acc, jmp or nop")),
+        }
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<Operation>, Error> {
+    let mut vec = Vec::new();
+
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        vec.push(Operation::from_str(line).map_err(|e| e.offset(i))?);
+    }
+
+    return Ok(vec);
+}
+
+/// How a run of a program ends.
+enum Ending {
+    Terminated(i32),
+    /// About to run an instruction for the second time.
+    Looped(i32),
+}
+
+/// Run the program until it terminates or loops, with the accumulator at that point. Fails on a
+/// jump outside of the program or an accumulator that overflows.
+fn run_program(inputs: &[Operation]) -> Result<Ending, Error> {
+    let mut accumulator: i32 = 0;
+    let mut index: i64 = 0;
+    let mut visited: HashSet<i64> = HashSet::new();
+
+    loop {
+        if index == inputs.len() as i64 {
+            return Ok(Ending::Terminated(accumulator));
+        }
+        if !visited.insert(index) {
+            return Ok(Ending::Looped(accumulator));
+        }
+
+        let operation = usize::try_from(index)
+            .ok()
+            .and_then(|i| inputs.get(i))
+            .ok_or_else(|| {
+                Error::NoSolution(format!(
+                    "the program jumps to instruction {}, outside of its {}",
+                    index + 1,
+                    inputs.len()
+                ))
+            })?;
+        match operation {
+            Operation::Acc(i) => {
+                accumulator = accumulator.checked_add(*i).ok_or_else(|| {
+                    Error::NoSolution(format!(
+                        "the accumulator overflows at instruction {}",
+                        index + 1
+                    ))
+                })?;
+                index += 1;
+            }
+            Operation::Jmp(i) => index += *i as i64,
+            Operation::Nop(_) => index += 1,
+        }
+    }
+}
+
+fn part_a(inputs: &[Operation]) -> Result<i32, Error> {
+    return match run_program(inputs)? {
+        Ending::Terminated(_) => Err(Error::NoSolution(
+            "the program terminates without looping".to_string(),
+        )),
+        Ending::Looped(accumulator) => Ok(accumulator),
+    };
+}
+
+fn part_b(inputs: &[Operation]) -> Result<i32, Error> {
+    // Every flip runs on its own copy, the first in program order that terminates wins.
+    let flips: Vec<usize> = (0..inputs.len())
+        .filter(|&m| !matches!(inputs[m], Operation::Acc(_)))
+        .collect();
+    let result = parallel::find_map_first(&flips, |&m| {
+        let mut program = inputs.to_vec();
+        program[m] = match &inputs[m] {
+            Operation::Jmp(i) => Operation::Nop(*i),
+            Operation::Nop(i) => Operation::Jmp(*i),
+            Operation::Acc(i) => Operation::Acc(*i),
+        };
+        // A flip that jumps out of the program or overflows does not fix it either.
+        return match run_program(&program) {
+            Ok(Ending::Terminated(accumulator)) => Some(accumulator),
+            _ => None,
+        };
+    });
+    return result.ok_or_else(|| {
+        Error::NoSolution("no single flipped instruction makes the program terminate".to_string())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n";
+
+    #[test]
+    fn example() {
+        let program = parse_inputs(EXAMPLE).unwrap();
+        assert_eq!(part_a(&program).unwrap(), 5);
+        assert_eq!(part_b(&program).unwrap(), 8);
+    }
+
+    #[test]
+    fn broken_programs() {
+        let program = parse_inputs("jmp -5\nnop +0").unwrap();
+        assert_eq!(
+            part_a(&program).unwrap_err().to_string(),
+            "no solution: the program jumps to instruction -4, outside of its 2"
+        );
+        let program = parse_inputs("acc +2147483647\nacc +1").unwrap();
+        assert!(part_a(&program).is_err());
+        assert!(part_a(&[]).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use aoc::params::{parse_param, unknown_param};
+use aoc::{parse_ints_from_str, stream, Error, Solution};
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
+
+/// Every number after the `preamble` should be a sum of two of the numbers before it.
+pub struct Day09 {
+    pub preamble: usize,
+}
+
+impl Default for Day09 {
+    fn default() -> Day09 {
+        return Day09 { preamble: 25 };
+    }
+}
+
+impl Solution for Day09 {
+    type Input = Vec<isize>;
+    type AnswerA = isize;
+    type AnswerB = isize;
+
+    fn day(&self) -> u8 {
+        return 9;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<isize>, Error> {
+        return parse_ints_from_str(input);
+    }
+
+    fn part_a(&self, ints: &Vec<isize>) -> Result<isize, Error> {
+        return part_a(ints, self.preamble);
+    }
+
+    fn part_b(&self, ints: &Vec<isize>) -> Result<isize, Error> {
+        // Part A to determine the target number, then find the contiguous set.
+        let contiguous_set = part_b(ints, part_a(ints, self.preamble)?)?;
+        return Ok(contiguous_set.iter().min().unwrap() + contiguous_set.iter().max().unwrap());
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return vec![("preamble", self.preamble.to_string())];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "preamble" => self.preamble = parse_param(name, value, "a number")?,
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+fn part_a(ints: &[isize], preamble: usize) -> Result<isize, Error> {
+    for i in preamble..ints.len() {
+        // The two numbers have to be at different positions in the preamble.
+        let mut found: bool = false;
+        for j in 1..(preamble + 1) {
+            for k in (j + 1)..(preamble + 1) {
+                if ints[i] == (ints[i - j] + ints[i - k]) {
+                    found = true;
+                }
+            }
+        }
+
+        if !found {
+            return Ok(ints[i]);
+        }
+    }
+
+    return Err(Error::NoSolution(
+        "every number is a sum of two preceding ones".to_string(),
+    ));
+}
+
+fn find_set(
+    ints: &[isize],
+    target: isize,
+    start: usize,
+    end: usize,
+) -> Result<HashSet<isize>, bool> {
+    let iter = ints[start..end].iter().copied();
+    let sum: isize = iter.clone().sum();
+    if sum == target {
+        return Ok(iter.collect());
+    }
+    Err(sum > target)
+}
+
+fn part_b(ints: &[isize], target: isize) -> Result<HashSet<isize>, Error> {
+    // A contiguous set holds at least two numbers.
+    for i in 0..ints.len() {
+        for j in (i + 2)..(ints.len() + 1) {
+            match find_set(ints, target, i, j) {
+                Ok(s) => return Ok(s),
+                Err(b) => {
+                    // If we overshot the target, break out of the loop.
+                    if b {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    return Err(Error::NoSolution(
+        "no contiguous set sums to the target".to_string(),
+    ));
+}
+
+/// Part A on a stream of numbers, only the preamble before the current number is kept.
+pub fn first_invalid<I>(ints: I, preamble: usize) -> Result<isize, Error>
+where
+    I: IntoIterator<Item = Result<isize, Error>>,
+{
+    let mut window: VecDeque<isize> = VecDeque::with_capacity(preamble + 1);
+
+    for int in ints {
+        let int = int?;
+        if window.len() == preamble {
+            let valid = window
+                .iter()
+                .enumerate()
+                .any(|(i, a)| window.iter().skip(i + 1).any(|b| a + b == int));
+            if !valid {
+                return Ok(int);
+            }
+            window.pop_front();
+        }
+        window.push_back(int);
+    }
+
+    return Err(Error::NoSolution(
+        "every number is a sum of two preceding ones".to_string(),
+    ));
+}
+
+/// Part B on a stream of positive numbers, only the run summing up to at most the target is kept.
+pub fn weakness<I>(ints: I, target: isize) -> Result<isize, Error>
+where
+    I: IntoIterator<Item = Result<isize, Error>>,
+{
+    let mut run: VecDeque<isize> = VecDeque::new();
+    let mut sum = 0;
+
+    for int in ints {
+        let int = int?;
+        run.push_back(int);
+        sum += int;
+
+        // Drop numbers from the front until the run is back under the target.
+        while sum > target {
+            sum -= run.pop_front().unwrap();
+        }
+        if sum == target && run.len() > 1 {
+            return Ok(run.iter().min().unwrap() + run.iter().max().unwrap());
+        }
+    }
+
+    return Err(Error::NoSolution(
+        "no contiguous set sums to the target".to_string(),
+    ));
+}
+
+/// Solve both parts reading the file twice, without holding it in memory.
+pub fn solve_streaming(path: &Path, preamble: usize) -> Result<(isize, isize), Error> {
+    let target = first_invalid(stream::ints(stream::open(path)?), preamble)?;
+    return Ok((target, weakness(stream::ints(stream::open(path)?), target)?));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576\n";
+
+    #[test]
+    fn example() {
+        let ints = parse_ints_from_str(EXAMPLE).unwrap();
+        assert_eq!(part_a(&ints, 5).unwrap(), 127);
+
+        let streamed = || stream::ints(EXAMPLE.as_bytes());
+        assert_eq!(first_invalid(streamed(), 5).unwrap(), 127);
+        assert_eq!(weakness(streamed(), 127).unwrap(), 62);
+
+        let mut day = Day09::default();
+        day.set_param("preamble", "5").unwrap();
+        let ints = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_b(&ints).unwrap(), 62);
+    }
+
+    proptest! {
+        #[test]
+        fn window_strategies_agree(
+            preamble in 2..6usize,
+            ints in prop::collection::vec(1..60isize, 6..40),
+        ) {
+            let streamed = || ints.iter().map(|i| Ok(*i));
+            let target = part_a(&ints, preamble).ok();
+            prop_assert_eq!(first_invalid(streamed(), preamble).ok(), target);
+
+            if let Some(target) = target {
+                let naive = part_b(&ints, target)
+                    .ok()
+                    .map(|s| s.iter().min().unwrap() + s.iter().max().unwrap());
+                prop_assert_eq!(weakness(streamed(), target).ok(), naive);
+            }
+        }
+    }
+}
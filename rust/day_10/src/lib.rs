@@ -0,0 +1,176 @@
+use aoc::{debug, parse_ints_from_str, Error, Solution};
+use std::collections::HashMap;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<isize>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 10;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<isize>, Error> {
+        return parse_ints_from_str(input);
+    }
+
+    fn part_a(&self, inputs: &Vec<isize>) -> Result<usize, Error> {
+        return part_a(inputs);
+    }
+
+    fn part_b(&self, inputs: &Vec<isize>) -> Result<usize, Error> {
+        debug!("B (dynamic programming): {}", part_b_dynamic(inputs)?);
+        return part_b(inputs);
+    }
+}
+
+/// The adapters in order, between the outlet at 0 jolts and the device 3 jolts above the highest.
+fn chain(inputs: &[isize]) -> Result<Vec<isize>, Error> {
+    if let Some(adapter) = inputs.iter().find(|a| **a < 1) {
+        return Err(Error::NoSolution(format!(
+            "an adapter of {} jolts does not fit",
+            adapter
+        )));
+    }
+    let device = inputs
+        .iter()
+        .max()
+        .ok_or_else(|| Error::NoSolution("there are no adapters".to_string()))?
+        .checked_add(3)
+        .ok_or_else(|| Error::NoSolution("the device joltage overflows".to_string()))?;
+
+    let mut chain = inputs.to_vec();
+    chain.push(0);
+    chain.push(device);
+    chain.sort();
+    return Ok(chain);
+}
+
+fn part_a(inputs: &[isize]) -> Result<usize, Error> {
+    let cloned_inputs = chain(inputs)?;
+
+    let mut differences = Vec::new();
+    for i in 1..cloned_inputs.len() {
+        differences.push(cloned_inputs[i] - cloned_inputs[i - 1]);
+    }
+
+    return Ok(differences.iter().filter(|x| **x == 1).count()
+        * differences.iter().filter(|x| **x == 3).count());
+}
+
+fn part_b(inputs: &[isize]) -> Result<usize, Error> {
+    let cloned_inputs = chain(inputs)?;
+
+    // Create differences vectors.
+    let mut differences = Vec::new();
+    for i in 1..cloned_inputs.len() {
+        differences.push(cloned_inputs[i] - cloned_inputs[i - 1]);
+    }
+
+    // Iterate over vector.
+    let mut result = 1;
+    for i in 0..differences.len() {
+        // Skip if the pointer is not at a one.
+        if differences[i] != 1 {
+            continue;
+        }
+
+        // Skip if previous was also a one.
+        if (i != 0) && (differences[i - 1] == 1) {
+            continue;
+        }
+
+        // Find block size with iteration forward.
+        let mut block_length: usize = 1;
+        for (j, difference) in differences.iter().enumerate().skip(i) {
+            if *difference != 1 {
+                block_length = j - i;
+                break;
+            }
+        }
+
+        if block_length == 2 {
+            result *= 2
+        } else if block_length == 3 {
+            result *= 4
+        } else if block_length == 4 {
+            result *= 7
+        }
+    }
+
+    return Ok(result);
+}
+
+fn find_ways(inputs: &[isize], pointer: usize, memory: &mut HashMap<usize, usize>) -> usize {
+    if let Some(ways) = memory.get(&pointer) {
+        return *ways;
+    }
+
+    if pointer == 0 {
+        return 1;
+    }
+
+    let mut ways = 0;
+
+    for i in 1..(pointer + 1) {
+        let difference: isize = inputs[pointer] - inputs[pointer - i];
+        if difference < 4 {
+            ways += find_ways(inputs, pointer - i, memory);
+        } else {
+            break;
+        }
+    }
+
+    memory.insert(pointer, ways);
+    return ways;
+}
+
+fn part_b_dynamic(inputs: &[isize]) -> Result<usize, Error> {
+    let cloned_inputs = chain(inputs)?;
+
+    // The memory is keyed on positions, so every input gets its own.
+    let mut memory = HashMap::new();
+    return Ok(find_ways(
+        &cloned_inputs,
+        cloned_inputs.len() - 1,
+        &mut memory,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Adapters in runs of at most four that differ by 1 jolt, separated by gaps of 3 jolts.
+    fn adapters() -> impl Strategy<Value = Vec<isize>> {
+        return prop::collection::vec(1..=4usize, 1..20).prop_flat_map(|runs| {
+            let mut adapters = Vec::new();
+            let mut joltage = 0;
+            for run in runs {
+                for _ in 0..run {
+                    joltage += 1;
+                    adapters.push(joltage);
+                }
+                joltage += 2;
+            }
+            return Just(adapters).prop_shuffle();
+        });
+    }
+
+    proptest! {
+        #[test]
+        fn arrangements_agree(adapters in adapters()) {
+            prop_assert_eq!(part_b(&adapters).unwrap(), part_b_dynamic(&adapters).unwrap());
+        }
+    }
+
+    #[test]
+    fn no_adapters() {
+        assert!(part_a(&[]).is_err());
+        assert!(part_b(&[3, 0]).is_err());
+        assert!(part_b_dynamic(&[isize::MAX]).is_err());
+    }
+}
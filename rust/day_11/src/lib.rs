@@ -0,0 +1,70 @@
+use aoc::automaton::{Automaton, DenseAutomaton, LineOfSight, Rule, SquareGrid};
+use aoc::grid::Grid;
+use aoc::{Error, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<char>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 11;
+    }
+
+    fn parse(&self, input: &str) -> Result<Grid<char>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, map: &Grid<char>) -> Result<usize, Error> {
+        return solve(map, true);
+    }
+
+    fn part_b(&self, map: &Grid<char>) -> Result<usize, Error> {
+        return solve(map, false);
+    }
+}
+
+const EMPTY: char = 'L';
+const FLOOR: char = '.';
+const FULL: char = '#';
+
+// Waiting areas settle long before this, it only guards against endless oscillation.
+const LIMIT: usize = 100_000;
+
+fn parse_inputs(input: &str) -> Result<Grid<char>, Error> {
+    return Grid::parse_with(input, "'L', '.' or '#'", |c| match c {
+        EMPTY | FLOOR | FULL => Some(c),
+        _ => None,
+    });
+}
+
+/// Seats are the cells, they fill up when nothing around them is taken and empty when crowded.
+fn solve(map: &Grid<char>, part_a: bool) -> Result<usize, Error> {
+    let full = map
+        .iter()
+        .filter(|(_, c)| **c == FULL)
+        .map(|((x, y), _)| (x as isize, y as isize));
+    let is_seat = |c: &char| *c != FLOOR;
+
+    let stable = if part_a {
+        let mut seats: DenseAutomaton<SquareGrid> = Automaton::new(
+            SquareGrid::from_grid(map, is_seat),
+            Rule::new(&[0], &[0, 1, 2, 3]),
+            full,
+        );
+        seats.run_until_stable(LIMIT).map(|_| seats.population())
+    } else {
+        let mut seats: DenseAutomaton<LineOfSight> = Automaton::new(
+            LineOfSight::from_grid(map, is_seat),
+            Rule::new(&[0], &[0, 1, 2, 3, 4]),
+            full,
+        );
+        seats.run_until_stable(LIMIT).map(|_| seats.population())
+    };
+
+    return stable.ok_or_else(|| {
+        Error::NoSolution(format!("the seats did not settle within {} rounds", LIMIT))
+    });
+}
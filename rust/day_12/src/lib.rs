@@ -0,0 +1,142 @@
+use aoc::{parse_at, parse_lines_from_str, Error, Solution};
+use regex::Regex;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(char, isize)>;
+    type AnswerA = isize;
+    type AnswerB = isize;
+
+    fn day(&self) -> u8 {
+        return 12;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<(char, isize)>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &Vec<(char, isize)>) -> Result<isize, Error> {
+        return Ok(get_distance(inputs, true));
+    }
+
+    fn part_b(&self, inputs: &Vec<(char, isize)>) -> Result<isize, Error> {
+        return Ok(get_distance(inputs, false));
+    }
+}
+
+const DIRS: [&char; 4] = [&'E', &'S', &'W', &'N'];
+
+fn parse_inputs(input: &str) -> Result<Vec<(char, isize)>, Error> {
+    let mut vec = Vec::new();
+    let re = Regex::new(r"^([NESWLRF])(\d+)$").unwrap();
+
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        let cap = match re.captures(line) {
+            Some(c) => c,
+            None => return Err(Error::parse(i + 1, 1, line, "an action like \"F10\"")),
+        };
+        vec.push((
+            cap[1].chars().next().unwrap(),
+            parse_at(&cap[2], i + 1, 2, "a number")?,
+        ));
+    }
+
+    return Ok(vec);
+}
+
+fn translate_inputs(inputs: &[(char, isize)]) -> Vec<(isize, isize)> {
+    let mut new_vec = Vec::new();
+
+    let mut dir_pointer: usize = 0;
+
+    for (ch, digits) in inputs {
+        let mut direction = *ch;
+
+        // Update the pointer and replace the direction.
+        if direction == 'L' {
+            let ticks: usize = (*digits / 90) as usize;
+            dir_pointer = (dir_pointer + 4 - ticks) % 4;
+            continue;
+        } else if direction == 'R' {
+            let ticks: usize = (*digits / 90) as usize;
+            dir_pointer = (dir_pointer + ticks) % 4;
+            continue;
+        }
+
+        // If we go forward, just replace the direction.
+        if direction == 'F' {
+            direction = *DIRS[dir_pointer];
+        }
+
+        // Push the newly improved (dx, dy)!
+        if direction == 'N' {
+            new_vec.push((*digits, 0));
+        } else if direction == 'S' {
+            new_vec.push((-*digits, 0));
+        } else if direction == 'E' {
+            new_vec.push((0, *digits));
+        } else if direction == 'W' {
+            new_vec.push((0, -*digits));
+        }
+    }
+
+    return new_vec;
+}
+
+fn translate_waypoint_inputs(inputs: &[(char, isize)]) -> Vec<(isize, isize)> {
+    let mut new_vec = Vec::new();
+
+    let mut waypoint: (isize, isize) = (1, 10);
+
+    for (direction, digits) in inputs {
+        // Rotate the waypoint.
+        if *direction == 'L' {
+            let ticks: usize = (*digits / 90) as usize;
+            for _ in 0..ticks {
+                waypoint = (waypoint.1, -waypoint.0)
+            }
+            continue;
+        } else if *direction == 'R' {
+            let ticks: usize = (*digits / 90) as usize;
+            for _ in 0..ticks {
+                waypoint = (-waypoint.1, waypoint.0)
+            }
+            continue;
+        }
+
+        // Move the waypoint.
+        if *direction == 'N' {
+            waypoint = (waypoint.0 + *digits, waypoint.1);
+            continue;
+        } else if *direction == 'S' {
+            waypoint = (waypoint.0 - *digits, waypoint.1);
+            continue;
+        } else if *direction == 'E' {
+            waypoint = (waypoint.0, waypoint.1 + *digits);
+            continue;
+        } else if *direction == 'W' {
+            waypoint = (waypoint.0, waypoint.1 - *digits);
+            continue;
+        }
+
+        // Move the ship.
+        if *direction == 'F' {
+            for _ in 0..*digits {
+                new_vec.push(waypoint);
+            }
+        }
+    }
+
+    return new_vec;
+}
+
+fn get_distance(inputs: &[(char, isize)], part_a: bool) -> isize {
+    let translated: Vec<(isize, isize)> = if part_a {
+        translate_inputs(inputs)
+    } else {
+        translate_waypoint_inputs(inputs)
+    };
+    return translated.iter().map(|t| t.0).sum::<isize>().abs()
+        + translated.iter().map(|t| t.1).sum::<isize>().abs();
+}
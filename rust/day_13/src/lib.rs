@@ -0,0 +1,167 @@
+use aoc::math::{chinese_remainder, lcm_all};
+use aoc::{parse_at, parse_lines_from_str, Error, Solution};
+use std::convert::TryFrom;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<isize>;
+    type AnswerA = isize;
+    type AnswerB = isize;
+
+    fn day(&self) -> u8 {
+        return 13;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<isize>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &Vec<isize>) -> Result<isize, Error> {
+        return part_a(&(inputs[0]), &inputs[1..]);
+    }
+
+    fn part_b(&self, inputs: &Vec<isize>) -> Result<isize, Error> {
+        return part_b(&inputs[1..]);
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<isize>, Error> {
+    let lines = parse_lines_from_str(input)?;
+    let mut vec = Vec::new();
+
+    // The first line holds the earliest departure.
+    let earliest = lines.first().map(|s| s.as_str()).unwrap_or("");
+    let earliest_leave: isize = parse_at(earliest, 1, 1, "an earliest departure time")?;
+    if earliest_leave < 0 {
+        return Err(Error::parse(
+            1,
+            1,
+            earliest,
+            "an earliest departure time of at least 0",
+        ));
+    }
+    vec.push(earliest_leave);
+
+    // The second line holds the bus IDs, where out of service buses are marked by an x and kept
+    // as 0, so the IDs themselves have to be positive.
+    let bus_ids = lines.get(1).map(|s| s.as_str()).unwrap_or("");
+    let mut column = 1;
+    for bus_id in bus_ids.split(',') {
+        match bus_id {
+            "x" => vec.push(0),
+            _ => {
+                let id: isize = parse_at(bus_id, 2, column, "a bus ID or x")?;
+                if id <= 0 {
+                    return Err(Error::parse(2, column, bus_id, "a bus ID above 0 or x"));
+                }
+                vec.push(id);
+            }
+        }
+        column += bus_id.len() + 1;
+    }
+
+    return Ok(vec);
+}
+
+fn part_a(earliest_leave: &isize, bus_ids: &[isize]) -> Result<isize, Error> {
+    let departures = bus_ids
+        .iter()
+        .filter(|b| **b != 0)
+        .map(|b| {
+            let departure = (*earliest_leave - (*earliest_leave % b))
+                .checked_add(*b)
+                .ok_or_else(|| {
+                    Error::NoSolution(format!("the next departure of bus {} overflows", b))
+                })?;
+            return Ok((departure, *b));
+        })
+        .collect::<Result<Vec<(isize, isize)>, Error>>()?;
+    let soonest = departures
+        .iter()
+        .min()
+        .ok_or_else(|| Error::NoSolution("all buses are out of service".to_string()))?;
+
+    return soonest
+        .1
+        .checked_mul(soonest.0 - *earliest_leave)
+        .ok_or_else(|| Error::NoSolution("the answer overflows".to_string()));
+}
+
+fn part_b(bus_ids: &[isize]) -> Result<isize, Error> {
+    // Bus i has to leave i minutes after the timestamp, so the timestamp is -i modulo its ID.
+    let congruences: Vec<(i128, i128)> = bus_ids
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| **b != 0)
+        .map(|(i, b)| (-(i as i128), *b as i128))
+        .collect();
+
+    // The timestamps repeat with the combined period, which has to fit before they can line up.
+    let moduli: Vec<i128> = congruences.iter().map(|(_, m)| *m).collect();
+    if lcm_all(&moduli).is_none() {
+        return Err(Error::NoSolution(
+            "the period of the buses overflows".to_string(),
+        ));
+    }
+
+    let (t, _) = chinese_remainder(&congruences)
+        .ok_or_else(|| Error::NoSolution("the buses never line up".to_string()))?;
+    return isize::try_from(t)
+        .map_err(|_| Error::NoSolution(format!("the timestamp {} overflows", t)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Try every timestamp up to the product of the bus IDs, after which the pattern repeats.
+    fn line_up_naive(bus_ids: &[isize]) -> Option<isize> {
+        let period: isize = bus_ids.iter().filter(|b| **b != 0).product();
+        return (0..period).find(|t| {
+            bus_ids
+                .iter()
+                .enumerate()
+                .all(|(i, b)| *b == 0 || (t + i as isize) % b == 0)
+        });
+    }
+
+    #[test]
+    fn overflow() {
+        let max = isize::MAX;
+        assert_eq!(
+            part_b(&[max, max - 1]).unwrap_err().to_string(),
+            format!(
+                "no solution: the timestamp {} overflows",
+                max as i128 * (max as i128 - 2)
+            )
+        );
+        assert_eq!(
+            part_b(&[max, max - 1, max - 2]).unwrap_err().to_string(),
+            "no solution: the period of the buses overflows"
+        );
+    }
+
+    #[test]
+    fn invalid_notes() {
+        assert_eq!(
+            parse_inputs("939\n7,0,x\n").unwrap_err().to_string(),
+            "line 2, column 3: unexpected \"0\", expected a bus ID above 0 or x"
+        );
+        assert!(parse_inputs("939\n7,-13\n").is_err());
+        assert!(parse_inputs("-939\n7,13\n").is_err());
+        assert_eq!(
+            part_a(&isize::MAX, &[2]).unwrap_err().to_string(),
+            "no solution: the next departure of bus 2 overflows"
+        );
+        assert_eq!(part_a(&939, &[7, 13, 0, 0, 59, 0, 31, 19]).unwrap(), 295);
+    }
+
+    proptest! {
+        #[test]
+        fn timestamps_agree(bus_ids in prop::collection::vec(prop_oneof![Just(0), 1..15isize], 1..6)) {
+            prop_assert_eq!(part_b(&bus_ids).ok(), line_up_naive(&bus_ids));
+        }
+    }
+}
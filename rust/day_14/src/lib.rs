@@ -0,0 +1,210 @@
+use aoc::record::{parse_records, Fields, Format, FromInput};
+use aoc::{Error, Solution};
+use std::collections::HashMap;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 14;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, instructions: &Vec<Instruction>) -> Result<usize, Error> {
+        return Ok(part_a(instructions).values().sum());
+    }
+
+    fn part_b(&self, instructions: &Vec<Instruction>) -> Result<usize, Error> {
+        return Ok(part_b(instructions).values().sum());
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mask(String),
+    Mem((usize, usize)),
+}
+
+impl FromInput for Instruction {
+    fn format() -> Format {
+        return Format::new("mask = {mask:word}")
+            .or("mem[{address:int}] = {value:int}")
+            .expected("\"mask = <36 bits>\" or \"mem[<address>] = <value>\"");
+    }
+
+    fn from_fields(fields: &Fields) -> Result<Instruction, Error> {
+        if fields.alternative() == 1 {
+            // The memory has 36 bit addresses and values, wider ones would slip past the masks.
+            let address: usize = fields.get("address")?;
+            let value: usize = fields.get("value")?;
+            if address >= 1 << 36 {
+                return Err(fields.error("address", "an address of at most 36 bits"));
+            }
+            if value >= 1 << 36 {
+                return Err(fields.error("value", "a value of at most 36 bits"));
+            }
+            return Ok(Instruction::Mem((address, value)));
+        }
+
+        let mask = fields.str("mask");
+        if mask.len() != 36 || mask.chars().any(|c| !"01X".contains(c)) {
+            return Err(fields.error("mask", "36 bits of 0, 1 or X"));
+        }
+        return Ok(Instruction::Mask(mask.to_string()));
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<Instruction>, Error> {
+    let instructions: Vec<Instruction> = parse_records(input)?;
+    if let Some(Instruction::Mem(_)) = instructions.first() {
+        let line = input.lines().next().unwrap_or("");
+        return Err(Error::parse(1, 1, line, "a mask before the first write"));
+    }
+    return Ok(instructions);
+}
+
+fn apply_mask_a(number: &usize, mask: &str) -> usize {
+    // The mask starts at the most significant bit, its 0s and 1s overwrite the bits of the number.
+    return mask
+        .chars()
+        .rev()
+        .enumerate()
+        .fold(*number, |n, (i, c)| match c {
+            '0' => n & !(1 << i),
+            '1' => n | (1 << i),
+            _ => n,
+        });
+}
+
+fn part_a(instructions: &[Instruction]) -> HashMap<usize, usize> {
+    let mut current_mask: String = "".to_string();
+    let mut memory = HashMap::new();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mask(m) => current_mask = m.clone(),
+            Instruction::Mem(t) => {
+                memory.insert(t.0, apply_mask_a(&t.1, &current_mask));
+            }
+        }
+    }
+
+    return memory;
+}
+
+fn to_bin(number: &usize) -> Vec<char> {
+    return format!("{:0>36}", format!("{:b}", number))
+        .chars()
+        .collect();
+}
+
+fn from_bin(bin_number: &[char]) -> usize {
+    return bin_number
+        .iter()
+        .fold(0, |n, c| (n << 1) | (*c == '1') as usize);
+}
+
+fn apply_floating_bits(input: &[char]) -> Vec<Vec<char>> {
+    match input.iter().position(|c| *c == 'X') {
+        Some(i) => {
+            let mut input_0 = input.to_vec();
+            input_0[i] = '0';
+            let mut input_1 = input.to_vec();
+            input_1[i] = '1';
+            let mut vec = apply_floating_bits(&input_0);
+            vec.extend(apply_floating_bits(&input_1));
+            vec
+        }
+        None => vec![input.to_vec()],
+    }
+}
+
+fn apply_mask_b(number: &usize, mask: &[char]) -> Vec<usize> {
+    let mut bin_number = to_bin(number);
+
+    // A 1 or X in the mask replaces the bit, a 0 keeps it.
+    for (bit, m) in bin_number.iter_mut().zip(mask) {
+        if *m != '0' {
+            *bit = *m;
+        }
+    }
+
+    let bin_numbers = apply_floating_bits(&bin_number);
+
+    return bin_numbers.iter().map(|b| from_bin(b)).collect();
+}
+
+fn part_b(instructions: &[Instruction]) -> HashMap<usize, usize> {
+    let mut current_mask: Vec<char> = Vec::new();
+    let mut memory = HashMap::new();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mask(m) => current_mask = m.clone().chars().collect(),
+            Instruction::Mem(t) => {
+                for mem in apply_mask_b(&t.0, &current_mask) {
+                    memory.insert(mem, t.1);
+                }
+            }
+        }
+    }
+
+    return memory;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            part_a(&[
+                Instruction::Mask("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X".to_string()),
+                Instruction::Mem((8, 11)),
+                Instruction::Mem((7, 101)),
+                Instruction::Mem((8, 0))
+            ])
+            .values()
+            .sum::<usize>(),
+            165
+        );
+    }
+
+    #[test]
+    fn invalid_programs() {
+        assert_eq!(
+            parse_inputs("mem[8] = 11\n").unwrap_err().to_string(),
+            "line 1, column 1: unexpected \"mem[8] = 11\", expected a mask before the first write"
+        );
+        let mask = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\n";
+        assert_eq!(
+            parse_inputs(&format!("{}mem[8] = 68719476736", mask))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 10: unexpected \"68719476736\", expected a value of at most 36 bits"
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            part_b(&[
+                Instruction::Mask("000000000000000000000000000000X1001X".to_string()),
+                Instruction::Mem((42, 100)),
+                Instruction::Mask("00000000000000000000000000000000X0XX".to_string()),
+                Instruction::Mem((26, 1))
+            ])
+            .values()
+            .sum::<usize>(),
+            208
+        );
+    }
+}
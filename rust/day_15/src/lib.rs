@@ -0,0 +1,135 @@
+use aoc::budget;
+use aoc::params::{parse_param, unknown_param};
+use aoc::{parse_ints_from_str, Error, Solution};
+use std::collections::HashMap;
+
+/// The number spoken on turn `turns_a` and `turns_b` of the memory game.
+pub struct Day15 {
+    pub turns_a: usize,
+    pub turns_b: usize,
+}
+
+impl Default for Day15 {
+    fn default() -> Day15 {
+        return Day15 {
+            turns_a: 2020,
+            turns_b: 30000000,
+        };
+    }
+}
+
+impl Solution for Day15 {
+    type Input = Vec<isize>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 15;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<isize>, Error> {
+        let inputs = parse_ints_from_str(input)?;
+        if inputs.is_empty() {
+            return Err(Error::parse(1, 1, "", "a starting number"));
+        }
+        return Ok(inputs);
+    }
+
+    fn part_a(&self, inputs: &Vec<isize>) -> Result<usize, Error> {
+        return play_game(inputs, self.turns_a);
+    }
+
+    fn part_b(&self, inputs: &Vec<isize>) -> Result<usize, Error> {
+        return play_game(inputs, self.turns_b);
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return vec![
+            ("turns_a", self.turns_a.to_string()),
+            ("turns_b", self.turns_b.to_string()),
+        ];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "turns_a" => self.turns_a = parse_param(name, value, "a number of turns")?,
+            "turns_b" => self.turns_b = parse_param(name, value, "a number of turns")?,
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+fn play_game(inputs: &[isize], duration: usize) -> Result<usize, Error> {
+    let mut tracker = budget::current().track("turns", Some(duration as u64));
+    let mut memory: HashMap<usize, usize> = HashMap::new();
+    let mut last_number: usize = *inputs
+        .first()
+        .expect("Input should have length greater than zero")
+        as usize;
+
+    for i in 1..duration {
+        tracker.step()?;
+        let next_number = match inputs.get(i) {
+            Some(m) => *m as usize,
+            None => match memory.get(&last_number) {
+                Some(n) => i - *n,
+                None => 0,
+            },
+        };
+        memory.insert(last_number, i);
+        last_number = next_number;
+    }
+
+    return Ok(last_number);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Look back through everything spoken so far on every turn.
+    fn play_naive(starting: &[isize], turns: usize) -> usize {
+        let mut spoken: Vec<usize> = Vec::new();
+        while spoken.len() < turns {
+            let next = match starting.get(spoken.len()) {
+                Some(n) => *n as usize,
+                None => {
+                    let (last, before) = spoken.split_last().unwrap();
+                    match before.iter().rposition(|n| n == last) {
+                        Some(i) => before.len() - i,
+                        None => 0,
+                    }
+                }
+            };
+            spoken.push(next);
+        }
+        return *spoken.last().unwrap();
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(play_game(&[0, 3, 6], 2020).unwrap(), 436);
+    }
+
+    #[test]
+    fn part2_example_1() {
+        assert_eq!(play_game(&[0, 3, 6], 30000000).unwrap(), 175594);
+    }
+
+    #[test]
+    fn part2_example_2() {
+        assert_eq!(play_game(&[1, 3, 2], 30000000).unwrap(), 2578);
+    }
+
+    proptest! {
+        #[test]
+        fn games_agree(
+            starting in prop::collection::vec(0..10isize, 1..6),
+            turns in 1..300usize,
+        ) {
+            prop_assert_eq!(play_game(&starting, turns).unwrap(), play_naive(&starting, turns));
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use aoc::budget;
+use aoc::record::{Fields, Format, FromInput};
+use aoc::{parse_at, parse_lines_from_str, Error, Solution};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = (Vec<Rule>, Vec<Vec<usize>>);
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 16;
+    }
+
+    fn parse(&self, input: &str) -> Result<(Vec<Rule>, Vec<Vec<usize>>), Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &(Vec<Rule>, Vec<Vec<usize>>)) -> Result<usize, Error> {
+        return Ok(find_error_rate(&inputs.0, &inputs.1));
+    }
+
+    fn part_b(&self, inputs: &(Vec<Rule>, Vec<Vec<usize>>)) -> Result<usize, Error> {
+        return part_2(&inputs.0, &inputs.1);
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    field: String,
+    lower: RangeInclusive<usize>,
+    upper: RangeInclusive<usize>,
+}
+
+impl Rule {
+    fn is_valid(&self, value: &usize) -> bool {
+        return self.lower.contains(value) || self.upper.contains(value);
+    }
+}
+
+impl FromInput for Rule {
+    fn format() -> Format {
+        return Format::new(
+            "{field}: {lower_min:int}-{lower_max:int} or {upper_min:int}-{upper_max:int}",
+        );
+    }
+
+    fn from_fields(fields: &Fields) -> Result<Rule, Error> {
+        return Ok(Rule {
+            field: fields.get("field")?,
+            lower: fields.get("lower_min")?..=fields.get("lower_max")?,
+            upper: fields.get("upper_min")?..=fields.get("upper_max")?,
+        });
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<(Vec<Rule>, Vec<Vec<usize>>), Error> {
+    let mut rules = Vec::new();
+    let mut tickets: Vec<Vec<usize>> = Vec::new();
+
+    let rule_format = Rule::format();
+
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        // Skip the blank lines and the section headers.
+        if line.is_empty() || line.ends_with(':') {
+            continue;
+        }
+
+        // Lines that are not rules have to be tickets.
+        if let Ok(fields) = rule_format.parse(line, i + 1) {
+            rules.push(Rule::from_fields(&fields)?);
+        } else {
+            let mut ticket = Vec::new();
+            let mut column = 1;
+            for value in line.split(',') {
+                ticket.push(parse_at(
+                    value,
+                    i + 1,
+                    column,
+                    "a rule or a comma-separated ticket",
+                )?);
+                column += value.len() + 1;
+            }
+
+            // Every ticket has the same fields, so the same number of values as your own.
+            if let Some(first) = tickets.first() {
+                if ticket.len() != first.len() {
+                    let expected = format!("a ticket of {} values", first.len());
+                    return Err(Error::parse(i + 1, 1, line, &expected));
+                }
+            }
+            tickets.push(ticket);
+        }
+    }
+
+    return Ok((rules, tickets));
+}
+
+fn find_field_error_rate(rules: &[Rule], field: &usize) -> usize {
+    return match rules
+        .iter()
+        .map(|r| r.is_valid(field))
+        .filter(|b| *b)
+        .count()
+    {
+        0 => *field,
+        _ => 0,
+    };
+}
+
+fn find_ticket_error_rate(rules: &[Rule], ticket: &[usize]) -> usize {
+    return ticket.iter().map(|f| find_field_error_rate(rules, f)).sum();
+}
+
+fn find_error_rate(rules: &[Rule], tickets: &[Vec<usize>]) -> usize {
+    return tickets
+        .iter()
+        .map(|t| find_ticket_error_rate(rules, t))
+        .sum();
+}
+
+fn find_valid_rule(rules: &[Rule], tickets: &[Vec<usize>], index: usize) -> Result<Rule, usize> {
+    let mut valid_rules = Vec::new();
+    for rule in rules {
+        if tickets
+            .iter()
+            .all(|t| t.get(index).is_some_and(|v| rule.is_valid(v)))
+        {
+            valid_rules.push(rule.clone());
+        }
+    }
+
+    match valid_rules.len() {
+        1 => Ok(valid_rules.remove(0)),
+        _ => Err(valid_rules.len()),
+    }
+}
+
+fn part_2(rules: &[Rule], tickets: &[Vec<usize>]) -> Result<usize, Error> {
+    let mut tracker = budget::current().track("passes", Some(rules.len() as u64));
+    let mut result: usize = 1;
+    let my_ticket = tickets
+        .first()
+        .ok_or_else(|| Error::NoSolution("there are no tickets".to_string()))?;
+    let valid_tickets: Vec<Vec<usize>> = tickets
+        .iter()
+        .filter(|t| find_ticket_error_rate(rules, t) == 0)
+        .cloned()
+        .collect();
+    let mut column_indices: HashSet<usize> = (0..my_ticket.len()).collect();
+    let mut unmapped_rules = rules.to_vec();
+
+    loop {
+        tracker.step()?;
+        tracker.check()?;
+
+        // Every pass has to map a column, or the next one would not either.
+        let mut mapped = false;
+        for index in column_indices.clone() {
+            match find_valid_rule(&unmapped_rules, &valid_tickets, index) {
+                Ok(r) => {
+                    mapped = true;
+                    if r.field.contains("departure") {
+                        result = result.checked_mul(my_ticket[index]).ok_or_else(|| {
+                            Error::NoSolution(
+                                "the product of the departure fields overflows".to_string(),
+                            )
+                        })?;
+                    }
+
+                    column_indices.remove(&index);
+                    unmapped_rules.retain(|p| *p != r);
+                    break;
+                }
+                Err(_) => continue,
+            }
+        }
+
+        if column_indices.is_empty() {
+            break;
+        }
+        if !mapped {
+            return Err(Error::NoSolution(format!(
+                "{} columns do not match exactly one field",
+                column_indices.len()
+            )));
+        }
+    }
+
+    return Ok(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            find_error_rate(
+                &[
+                    Rule {
+                        field: "class".to_string(),
+                        lower: 1..=3,
+                        upper: 5..=7
+                    },
+                    Rule {
+                        field: "row".to_string(),
+                        lower: 6..=11,
+                        upper: 33..=44
+                    },
+                    Rule {
+                        field: "seat".to_string(),
+                        lower: 13..=40,
+                        upper: 45..=50
+                    }
+                ],
+                &[
+                    vec![7, 1, 14],
+                    vec![7, 3, 47],
+                    vec![40, 4, 50],
+                    vec![55, 2, 20],
+                    vec![38, 6, 12]
+                ]
+            ),
+            71
+        );
+    }
+
+    #[test]
+    fn missing_tickets() {
+        assert!(part_2(&[], &[]).is_err());
+        assert_eq!(
+            parse_inputs("your ticket:\n1,2\n\nnearby tickets:\n3\n")
+                .unwrap_err()
+                .to_string(),
+            "line 5, column 1: unexpected \"3\", expected a ticket of 2 values"
+        );
+    }
+
+    #[test]
+    fn ambiguous_fields() {
+        let rule = |field: &str| Rule {
+            field: field.to_string(),
+            lower: 1..=3,
+            upper: 5..=7,
+        };
+        let error = part_2(&[rule("a"), rule("b")], &[vec![1, 2], vec![2, 1]]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no solution: 2 columns do not match exactly one field"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use aoc::automaton::{Automaton, Lattice, Rule, SparseAutomaton};
+use aoc::params::{parse_param, unknown_param};
+use aoc::{parse_lines_from_str, Error, Solution};
+
+/// Run the pocket dimension for a number of `cycles`.
+pub struct Day17 {
+    pub cycles: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Day17 {
+        return Day17 { cycles: 6 };
+    }
+}
+
+impl Solution for Day17 {
+    type Input = Vec<(isize, isize)>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 17;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<(isize, isize)>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &Vec<(isize, isize)>) -> Result<usize, Error> {
+        return Ok(solve::<3>(inputs, self.cycles));
+    }
+
+    fn part_b(&self, inputs: &Vec<(isize, isize)>) -> Result<usize, Error> {
+        return Ok(solve::<4>(inputs, self.cycles));
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return vec![("cycles", self.cycles.to_string())];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "cycles" => self.cycles = parse_param(name, value, "a number of cycles")?,
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+/// The active cubes of the starting slice, as `(x, y)`.
+fn parse_inputs(input: &str) -> Result<Vec<(isize, isize)>, Error> {
+    let mut starting_state = Vec::new();
+
+    for (x, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        for (y, char) in line.chars().enumerate() {
+            if char != '#' && char != '.' {
+                return Err(Error::parse(x + 1, y + 1, &char.to_string(), "'.' or '#'"));
+            }
+            if char == '#' {
+                starting_state.push((x as isize, y as isize));
+            }
+        }
+    }
+
+    return Ok(starting_state);
+}
+
+/// Run cycles of Conway's rules in `N` dimensions, the starting slice has zeroes elsewhere.
+fn solve<const N: usize>(inputs: &[(isize, isize)], cycles: usize) -> usize {
+    let cubes = inputs.iter().map(|(x, y)| {
+        let mut cube = [0; N];
+        cube[0] = *x;
+        cube[1] = *y;
+        cube
+    });
+    let mut pocket: SparseAutomaton<Lattice<N>> = Automaton::new(Lattice, Rule::conway(), cubes);
+    pocket.run(cycles);
+
+    return pocket.population();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let set = vec![(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)];
+        assert_eq!(solve::<3>(&set, 6), 112);
+        assert_eq!(solve::<4>(&set, 6), 848);
+    }
+}
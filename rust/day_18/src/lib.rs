@@ -0,0 +1,228 @@
+use aoc::{parse_at, parse_lines_from_str, Error, Solution};
+use regex::Regex;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<String>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 18;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, eqs: &Vec<String>) -> Result<usize, Error> {
+        return solve(eqs, &true);
+    }
+
+    fn part_b(&self, eqs: &Vec<String>) -> Result<usize, Error> {
+        return solve(eqs, &false);
+    }
+}
+
+fn solve_simple_eq(eq: &str, part_a: &bool) -> Result<usize, Error> {
+    if *part_a || !eq.contains('+') {
+        let mut eq_iter = eq.split(' ');
+        let mut result = operand(eq_iter.next())?;
+
+        loop {
+            match eq_iter.next() {
+                Some("+") => result = overflow(result.checked_add(operand(eq_iter.next())?))?,
+                Some("*") => result = overflow(result.checked_mul(operand(eq_iter.next())?))?,
+                Some(c) => {
+                    return Err(Error::NoSolution(format!("unexpected {:?} in {:?}", c, eq)))
+                }
+                None => return Ok(result),
+            }
+        }
+    } else {
+        let mut mut_eq = eq.to_string();
+
+        let priority_reqex = Regex::new(r"(\d+) \+ (\d+)").unwrap();
+        loop {
+            match priority_reqex.captures_iter(&mut_eq).next() {
+                Some(c) => {
+                    let sum = operand(Some(&c[1]))?.checked_add(operand(Some(&c[2]))?);
+                    let sol = overflow(sum)?;
+                    // Only replace this sum, the same text can be part of a longer one elsewhere.
+                    let range = c.get(0).unwrap().range();
+                    mut_eq.replace_range(range, &sol.to_string());
+                }
+                None => return solve_simple_eq(&mut_eq, part_a),
+            }
+        }
+    }
+}
+
+/// A number of an expression, which the parser already checked.
+fn operand(token: Option<&str>) -> Result<usize, Error> {
+    let token = token.unwrap_or("");
+    return token
+        .parse()
+        .map_err(|_| Error::NoSolution(format!("expected a number, got {:?}", token)));
+}
+
+fn overflow(value: Option<usize>) -> Result<usize, Error> {
+    return value.ok_or_else(|| Error::NoSolution("the expression overflows".to_string()));
+}
+
+fn solve_eq(eq: &str, part_a: &bool) -> Result<usize, Error> {
+    let mut mut_eq = eq.to_string();
+
+    let nested_regex = Regex::new(r"\(([^\)^\(]+)\)").unwrap();
+    loop {
+        match nested_regex.captures_iter(&mut_eq).next() {
+            Some(c) => {
+                let sol = solve_simple_eq(&c[1], part_a)?;
+                mut_eq = mut_eq.replace(&c[0], &sol.to_string());
+            }
+            None => return solve_simple_eq(&mut_eq, part_a),
+        }
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<String>, Error> {
+    let mut eqs = Vec::new();
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        eqs.push(parse_eq(line).map_err(|e| e.offset(i))?);
+    }
+    return Ok(eqs);
+}
+
+/// Check that an expression alternates numbers and operators with balanced parentheses, and
+/// space it the way the solver splits it, like `1 + (2 * 3)`.
+fn parse_eq(line: &str) -> Result<String, Error> {
+    const OPERAND: &str = "a number or '('";
+    const OPERATOR: &str = "'+', '*' or ')'";
+
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens: Vec<String> = Vec::new();
+    let mut depth: usize = 0;
+    let mut operand_next = true;
+    let mut j = 0;
+    while j < chars.len() {
+        let c = chars[j];
+        match c {
+            ' ' => {}
+            '0'..='9' if operand_next => {
+                let end = chars[j..]
+                    .iter()
+                    .position(|c| !c.is_ascii_digit())
+                    .map_or(chars.len(), |p| j + p);
+                let number: String = chars[j..end].iter().collect();
+                parse_at::<usize>(&number, 1, j + 1, "a number")?;
+                tokens.push(number);
+                operand_next = false;
+                j = end;
+                continue;
+            }
+            '(' if operand_next => depth += 1,
+            ')' if !operand_next && depth > 0 => depth -= 1,
+            '+' | '*' if !operand_next => operand_next = true,
+            _ => {
+                let expected = if operand_next { OPERAND } else { OPERATOR };
+                return Err(Error::parse(1, j + 1, &c.to_string(), expected));
+            }
+        }
+        if c != ' ' {
+            tokens.push(c.to_string());
+        }
+        j += 1;
+    }
+    if operand_next {
+        return Err(Error::parse(1, chars.len() + 1, "", OPERAND));
+    }
+    if depth > 0 {
+        return Err(Error::parse(1, chars.len() + 1, "", "')'"));
+    }
+
+    let mut eq = String::new();
+    for token in tokens {
+        if !eq.is_empty() && !eq.ends_with('(') && token != ")" {
+            eq.push(' ');
+        }
+        eq.push_str(&token);
+    }
+    return Ok(eq);
+}
+
+fn solve(eqs: &[String], part_a: &bool) -> Result<usize, Error> {
+    return eqs.iter().try_fold(0, |sum, e| {
+        overflow(usize::checked_add(sum, solve_eq(e, part_a)?))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_a() {
+        assert_eq!(
+            solve(
+                &[
+                    "2 * 3 + (4 * 5)".to_string(),
+                    "5 + (8 * 3 + 9 + 3 * 4 * 3)".to_string(),
+                    "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))".to_string(),
+                    "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2".to_string()
+                ],
+                &true
+            )
+            .unwrap(),
+            26335
+        );
+    }
+
+    #[test]
+    fn example_b() {
+        assert_eq!(
+            solve(
+                &[
+                    "1 + (2 * 3) + (4 * (5 + 6))".to_string(),
+                    "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2".to_string(),
+                ],
+                &false
+            )
+            .unwrap(),
+            23391
+        );
+    }
+
+    #[test]
+    fn overlapping_sums() {
+        assert_eq!(
+            solve(&["5 * 5 + 7 * 5 + 7776".to_string()], &false).unwrap(),
+            5 * 12 * 7781
+        );
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        assert_eq!(
+            parse_inputs("1+(2*3)\n").unwrap(),
+            vec!["1 + (2 * 3)".to_string()]
+        );
+        let error = |input: &str| parse_inputs(input).unwrap_err().to_string();
+        assert_eq!(
+            error("1 + 2\n3 +"),
+            "line 2, column 4: expected a number or '('"
+        );
+        assert_eq!(
+            error("1 2"),
+            "line 1, column 3: unexpected \"2\", expected '+', '*' or ')'"
+        );
+        assert_eq!(
+            error("()"),
+            "line 1, column 2: unexpected \")\", expected a number or '('"
+        );
+        assert_eq!(error("(1 + 2"), "line 1, column 7: expected ')'");
+
+        let eqs = parse_inputs("99999999999 * 99999999999").unwrap();
+        assert!(solve(&eqs, &true).is_err());
+    }
+}
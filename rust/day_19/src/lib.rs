@@ -0,0 +1,213 @@
+use aoc::budget;
+use aoc::{parallel, parse_at, parse_lines_from_str, Error, Solution};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (HashMap<isize, Rule>, Vec<String>);
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 19;
+    }
+
+    fn parse(&self, input: &str) -> Result<(HashMap<isize, Rule>, Vec<String>), Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &(HashMap<isize, Rule>, Vec<String>)) -> Result<usize, Error> {
+        return solve(&inputs.0, &inputs.1);
+    }
+
+    fn part_b(&self, inputs: &(HashMap<isize, Rule>, Vec<String>)) -> Result<usize, Error> {
+        let mut new_rules = inputs.0.clone();
+        new_rules.insert(8, Rule::Split((vec![42], vec![42, 8])));
+        new_rules.insert(11, Rule::Split((vec![42, 31], vec![42, 11, 31])));
+
+        return solve(&new_rules, &inputs.1);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Rule {
+    Base(char),
+    Concat(Vec<isize>),
+    Split((Vec<isize>, Vec<isize>)),
+}
+
+fn cartesian_product(a: &[String], b: &[String], messages: &[String]) -> Vec<String> {
+    let mut result = Vec::new();
+    for x in a {
+        for y in b {
+            let new_vec = x.clone() + y;
+            if messages.iter().filter(|&m| m.contains(&new_vec)).count() > 0 {
+                result.push(new_vec);
+            }
+        }
+    }
+    return result;
+}
+
+impl Rule {
+    fn expand(
+        &self,
+        others: &HashMap<isize, Rule>,
+        max_recursion_depth: &usize,
+        recursion_depth: &usize,
+        messages: &[String],
+    ) -> Vec<String> {
+        return match self {
+            Rule::Base(c) => vec![c.clone().to_string()],
+            Rule::Concat(v) => {
+                let mut result = vec!["".to_string()];
+                for rule_id in v {
+                    result = cartesian_product(
+                        &result,
+                        &others[rule_id].expand(
+                            others,
+                            max_recursion_depth,
+                            recursion_depth,
+                            messages,
+                        ),
+                        messages,
+                    );
+                }
+                result
+            }
+            Rule::Split(t) => {
+                let depth: usize = if t.1.contains(&8) || t.1.contains(&11) {
+                    recursion_depth + 1
+                } else {
+                    0
+                };
+
+                let mut first =
+                    Rule::Concat(t.0.clone()).expand(others, max_recursion_depth, &depth, messages);
+                if depth < *max_recursion_depth {
+                    first.extend(Rule::Concat(t.1.clone()).expand(
+                        others,
+                        max_recursion_depth,
+                        &depth,
+                        messages,
+                    ));
+                }
+                first
+            }
+        };
+    }
+}
+
+fn solve_one(rules: &HashMap<isize, Rule>, message: String) -> bool {
+    let whitelist: HashSet<String> = rules
+        .get(&0)
+        .unwrap()
+        .expand(rules, &8, &0, std::slice::from_ref(&message))
+        .iter()
+        .cloned()
+        .collect();
+    return whitelist.contains(&message);
+}
+
+fn solve(rules: &HashMap<isize, Rule>, messages: &[String]) -> Result<usize, Error> {
+    let tracker = Mutex::new(budget::current().track("messages", Some(messages.len() as u64)));
+    let matches = parallel::map(messages, |message| -> Result<bool, Error> {
+        // Expanding the rules for a message is slow, so check the budget on every one.
+        {
+            let mut tracker = tracker.lock().unwrap();
+            tracker.step()?;
+            tracker.check()?;
+        }
+        return Ok(solve_one(rules, message.clone()));
+    });
+
+    let mut count = 0;
+    for matched in matches {
+        if matched? {
+            count += 1;
+        }
+    }
+    return Ok(count);
+}
+
+fn parse_rule_ids(ids: &str, line: usize, column: usize) -> Result<Vec<isize>, Error> {
+    let mut vec = Vec::new();
+    let mut column = column;
+    for id in ids.split(' ') {
+        vec.push(parse_at(id, line, column, "a rule number")?);
+        column += id.len() + 1;
+    }
+    return Ok(vec);
+}
+
+fn parse_inputs(input: &str) -> Result<(HashMap<isize, Rule>, Vec<String>), Error> {
+    let mut rules = HashMap::new();
+    let mut messages = Vec::new();
+
+    let rule_regex_base = Regex::new(r#"^(\d+): "([ab])"$"#).unwrap();
+    let rule_regex_concat = Regex::new(r"^(\d+): ([\d\s]+)$").unwrap();
+    let rule_regex_split = Regex::new(r"^(\d+): ([\d\s]+) \| ([\d\s]+)$").unwrap();
+    let message_regex = Regex::new(r"^([ab]+)$").unwrap();
+
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        if let Some(c) = rule_regex_base.captures(line) {
+            rules.insert(
+                parse_at(&c[1], i + 1, 1, "a rule number")?,
+                Rule::Base(c[2].chars().next().unwrap()),
+            );
+        } else if let Some(c) = rule_regex_concat.captures(line) {
+            rules.insert(
+                parse_at(&c[1], i + 1, 1, "a rule number")?,
+                Rule::Concat(parse_rule_ids(&c[2], i + 1, c.get(2).unwrap().start() + 1)?),
+            );
+        } else if let Some(c) = rule_regex_split.captures(line) {
+            rules.insert(
+                parse_at(&c[1], i + 1, 1, "a rule number")?,
+                Rule::Split((
+                    parse_rule_ids(&c[2], i + 1, c.get(2).unwrap().start() + 1)?,
+                    parse_rule_ids(&c[3], i + 1, c.get(3).unwrap().start() + 1)?,
+                )),
+            );
+        } else if let Some(c) = message_regex.captures(line) {
+            messages.push(c[1].to_string());
+        } else if !line.is_empty() {
+            return Err(Error::parse(
+                i + 1,
+                1,
+                line,
+                "a rule or a message of a's and b's",
+            ));
+        }
+    }
+
+    return Ok((rules, messages));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_a() {
+        let mut rules = HashMap::new();
+        rules.insert(0, Rule::Concat(vec![4, 1, 5]));
+        rules.insert(1, Rule::Split((vec![2, 3], vec![3, 2])));
+        rules.insert(2, Rule::Split((vec![4, 4], vec![5, 5])));
+        rules.insert(3, Rule::Split((vec![4, 5], vec![5, 4])));
+        rules.insert(4, Rule::Base('a'));
+        rules.insert(5, Rule::Base('b'));
+
+        let messages = vec![
+            "ababbb".to_string(),
+            "bababa".to_string(),
+            "abbbab".to_string(),
+            "aaabbb".to_string(),
+            "aaaabbb".to_string(),
+        ];
+
+        assert_eq!(solve(&rules, &messages).unwrap(), 2);
+    }
+}
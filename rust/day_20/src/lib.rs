@@ -0,0 +1,464 @@
+use aoc::{debug, info, parallel, parse_at, parse_blocks_from_str, Error, Solution};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Picture>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 20;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Picture>, Error> {
+        let tiles = parse_inputs(input)?;
+        info!("Loaded {} tiles", tiles.len());
+        return Ok(tiles);
+    }
+
+    fn part_a(&self, tiles: &Vec<Picture>) -> Result<usize, Error> {
+        return part_a(tiles);
+    }
+
+    fn part_b(&self, tiles: &Vec<Picture>) -> Result<usize, Error> {
+        return part_b(tiles);
+    }
+}
+
+const PIXEL: char = '#';
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    Up(),
+    Down(),
+    Left(),
+    Right(),
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Pixel {
+    x: isize,
+    y: isize,
+}
+
+#[derive(Clone, Debug, Eq)]
+pub struct Picture {
+    id: Option<usize>,
+    pixels: HashSet<Pixel>,
+}
+
+impl PartialEq for Picture {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Picture {
+    // A picture without pixels has its corners at the origin.
+    fn get_min_x(&self) -> isize {
+        return self.pixels.iter().map(|t| t.x).min().unwrap_or(0);
+    }
+
+    fn get_min_y(&self) -> isize {
+        return self.pixels.iter().map(|t| t.y).min().unwrap_or(0);
+    }
+
+    fn get_max_x(&self) -> isize {
+        return self.pixels.iter().map(|t| t.x).max().unwrap_or(0);
+    }
+
+    fn get_max_y(&self) -> isize {
+        return self.pixels.iter().map(|t| t.y).max().unwrap_or(0);
+    }
+
+    fn flip_x(&self) -> Picture {
+        let max_x = self.get_max_x();
+        return Picture {
+            id: self.id,
+            pixels: self
+                .pixels
+                .iter()
+                .map(|p| Pixel {
+                    x: max_x - p.x,
+                    y: p.y,
+                })
+                .collect(),
+        };
+    }
+
+    fn flip_y(&self) -> Picture {
+        let max_y = self.get_max_y();
+        return Picture {
+            id: self.id,
+            pixels: self
+                .pixels
+                .iter()
+                .map(|p| Pixel {
+                    x: p.x,
+                    y: max_y - p.y,
+                })
+                .collect(),
+        };
+    }
+
+    fn rotate(&self) -> Picture {
+        let max_x = self.get_max_x();
+        return Picture {
+            id: self.id,
+            pixels: self
+                .pixels
+                .iter()
+                .map(|p| Pixel {
+                    x: p.y,
+                    y: max_x - p.x,
+                })
+                .collect(),
+        };
+    }
+
+    fn get_border(&self, direction: Direction) -> HashSet<isize> {
+        let max_x = self.get_max_x();
+        let max_y = self.get_max_y();
+
+        match direction {
+            Direction::Up() => self
+                .pixels
+                .iter()
+                .filter(|&t| t.y == 0)
+                .map(|t| t.x)
+                .collect(),
+            Direction::Down() => self
+                .pixels
+                .iter()
+                .filter(|&t| t.y == max_y)
+                .map(|t| t.x)
+                .collect(),
+            Direction::Left() => self
+                .pixels
+                .iter()
+                .filter(|&t| t.x == 0)
+                .map(|t| t.y)
+                .collect(),
+            Direction::Right() => self
+                .pixels
+                .iter()
+                .filter(|&t| t.x == max_x)
+                .map(|t| t.y)
+                .collect(),
+        }
+    }
+
+    fn get_permutations(&self) -> Vec<Picture> {
+        vec![
+            self.clone(),
+            self.flip_x(),
+            self.flip_y(),
+            self.rotate(),
+            self.flip_x().flip_y(),
+            self.flip_x().rotate(),
+            self.flip_y().rotate(),
+            self.flip_x().flip_y().rotate(),
+        ]
+    }
+
+    fn is_neighbour(&self, other: &Picture, direction: Direction) -> bool {
+        match direction {
+            Direction::Up() => {
+                self.get_border(Direction::Up()) == other.get_border(Direction::Down())
+            }
+            Direction::Down() => {
+                self.get_border(Direction::Down()) == other.get_border(Direction::Up())
+            }
+            Direction::Left() => {
+                self.get_border(Direction::Left()) == other.get_border(Direction::Right())
+            }
+            Direction::Right() => {
+                self.get_border(Direction::Right()) == other.get_border(Direction::Left())
+            }
+        }
+    }
+
+    fn to_string(&self, monsters: &[Picture]) -> String {
+        let mut string = "".to_string();
+        for y in self.get_min_y()..=self.get_max_y() {
+            for x in self.get_min_x()..=self.get_max_x() {
+                if self.pixels.contains(&Pixel { x, y }) {
+                    let mut is_monster = false;
+
+                    for monster in monsters {
+                        if monster.pixels.contains(&Pixel { x, y }) {
+                            is_monster = true;
+                        }
+                    }
+
+                    if is_monster {
+                        string += "0";
+                    } else {
+                        string += "#";
+                    }
+                } else {
+                    string += ".";
+                }
+            }
+            string += "\n";
+        }
+        return string;
+    }
+}
+
+impl FromStr for Picture {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Picture, Self::Err> {
+        let mut input_iter = input.split('\n');
+
+        // Parse the identifier.
+        let header = input_iter.next().unwrap_or("");
+        let id: usize = match header
+            .strip_prefix("Tile ")
+            .and_then(|h| h.strip_suffix(':'))
+        {
+            Some(i) => parse_at(i, 1, 6, "a tile number")?,
+            None => return Err(Error::parse(1, 1, header, "a header like \"Tile 1234:\"")),
+        };
+
+        // Parse the pixels.
+        let mut pixels = HashSet::new();
+        for (y, line) in input_iter.enumerate() {
+            for (x, chr) in line.chars().enumerate() {
+                if chr != PIXEL && chr != '.' {
+                    return Err(Error::parse(y + 2, x + 1, &chr.to_string(), "'.' or '#'"));
+                }
+                if chr == PIXEL {
+                    pixels.insert(Pixel {
+                        x: x as isize,
+                        y: y as isize,
+                    });
+                }
+            }
+        }
+
+        return Ok(Picture {
+            id: Some(id),
+            pixels,
+        });
+    }
+}
+
+fn count_neighbours(tile: &Picture, others: &[Picture]) -> usize {
+    let mut count = 0;
+    for other in others {
+        if other.id == tile.id {
+            continue;
+        }
+
+        let mut found = false;
+        for permutation in other.get_permutations() {
+            for direction in [
+                Direction::Down(),
+                Direction::Up(),
+                Direction::Right(),
+                Direction::Left(),
+            ] {
+                if tile.is_neighbour(&permutation, direction) && !found {
+                    count += 1;
+                    found = true;
+                }
+            }
+        }
+    }
+    return count;
+}
+
+fn part_a(tiles: &[Picture]) -> Result<usize, Error> {
+    let neighbours = parallel::map(tiles, |t| count_neighbours(t, tiles));
+    let corners: Vec<usize> = tiles
+        .iter()
+        .zip(neighbours)
+        .filter(|(_, n)| *n == 2)
+        .filter_map(|(t, _)| t.id)
+        .collect();
+    if corners.len() != 4 {
+        return Err(Error::NoSolution(format!(
+            "{} tiles have two neighbours, a square has 4 corners",
+            corners.len()
+        )));
+    }
+    return corners
+        .iter()
+        .try_fold(1usize, |product, id| product.checked_mul(*id))
+        .ok_or_else(|| Error::NoSolution("the product of the corner tiles overflows".to_string()));
+}
+
+fn create_composite(tiles: &[Picture]) -> Result<Picture, Error> {
+    // Keep the placed tiles in a hashmap.
+    let mut placed: HashMap<(isize, isize), Picture> = HashMap::new();
+    let mut unplaced = tiles.to_vec();
+
+    // Insert a starting piece.
+    let first = tiles
+        .first()
+        .ok_or_else(|| Error::NoSolution("there are no tiles".to_string()))?;
+    placed.insert((0, 0), first.clone());
+    unplaced.retain(|x| x != first);
+
+    loop {
+        // Every pass has to place a tile, or the rest never fits.
+        let remaining = unplaced.len();
+        for t in unplaced.clone() {
+            for (k, v) in placed.clone().iter() {
+                for candidate in t.get_permutations() {
+                    for border in [
+                        Direction::Down(),
+                        Direction::Up(),
+                        Direction::Right(),
+                        Direction::Left(),
+                    ] {
+                        if v.is_neighbour(&candidate, border) {
+                            let dx;
+                            let dy;
+
+                            match border {
+                                Direction::Down() => {
+                                    dx = 0;
+                                    dy = 1;
+                                }
+                                Direction::Up() => {
+                                    dx = 0;
+                                    dy = -1;
+                                }
+                                Direction::Left() => {
+                                    dx = -1;
+                                    dy = 0;
+                                }
+                                Direction::Right() => {
+                                    dx = 1;
+                                    dy = 0;
+                                }
+                            }
+
+                            placed.insert((k.0 + dx, k.1 + dy), candidate.clone());
+                            unplaced.retain(|x| x != &t);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        if unplaced.is_empty() {
+            break;
+        }
+        if unplaced.len() == remaining {
+            return Err(Error::NoSolution(format!(
+                "{} tiles do not fit next to the others",
+                remaining
+            )));
+        }
+    }
+
+    // Add each tiles to the composite respecting the x and y offset.
+    let mut composite: HashSet<Pixel> = HashSet::new();
+
+    for (k, v) in placed.iter() {
+        let max_x = v.get_max_x();
+        let max_y = v.get_max_y();
+        for t in v.pixels.clone() {
+            if (t.x != 0) && (t.y != 0) && (t.x != max_x) && (t.y != max_y) {
+                composite.insert(Pixel {
+                    x: (k.0 * (max_x - 1)) + t.x,
+                    y: (k.1 * (max_y - 1)) + t.y,
+                });
+            }
+        }
+    }
+
+    return Ok(Picture {
+        id: None,
+        pixels: composite,
+    });
+}
+
+fn get_seamonster(dx: isize, dy: isize) -> Picture {
+    let mut monster = HashSet::new();
+    let monster_strings = [
+        "                  # ".to_string(),
+        "#    ##    ##    ###".to_string(),
+        " #  #  #  #  #  #   ".to_string(),
+    ];
+
+    for (y, line) in monster_strings.iter().enumerate() {
+        for (x, chr) in line.chars().enumerate() {
+            if chr == PIXEL {
+                monster.insert(Pixel {
+                    x: x as isize + dx,
+                    y: y as isize + dy - 1,
+                });
+            }
+        }
+    }
+
+    return Picture {
+        id: None,
+        pixels: monster,
+    };
+}
+
+fn count_seamonsters(composite: &Picture) -> usize {
+    let permutations = composite.get_permutations();
+    let found = parallel::map(&permutations, |permutation| {
+        return permutation
+            .pixels
+            .iter()
+            .map(|pixel| get_seamonster(pixel.x, pixel.y))
+            .filter(|monster| monster.pixels.is_subset(&permutation.pixels))
+            .collect::<Vec<Picture>>();
+    });
+
+    let mut monsters = Vec::new();
+    for (permutation, found) in permutations.iter().zip(found) {
+        if !found.is_empty() {
+            monsters.extend(found);
+            debug!(
+                "Found some in this orientation:\n\n{}",
+                &permutation.to_string(&monsters)
+            );
+        }
+    }
+    return monsters.len();
+}
+
+fn part_b(tiles: &[Picture]) -> Result<usize, Error> {
+    // Get the composite image from the tiles.
+    let composite = create_composite(tiles)?;
+    debug!("There are {} waves/monster bits", composite.pixels.len());
+
+    // Count the number of monsters in the composite image.
+    let monster_count = count_seamonsters(&composite);
+    info!("Found {} monsters", monster_count);
+
+    // Subtract this number of monsters from the length of the composite image.
+    return composite
+        .pixels
+        .len()
+        .checked_sub(monster_count * get_seamonster(0, 0).pixels.len())
+        .ok_or_else(|| Error::NoSolution("the monsters overlap".to_string()));
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<Picture>, Error> {
+    let mut vec = Vec::new();
+
+    // Iterate over all tiles, keeping track of the line they start on.
+    let mut line = 0;
+    for block in parse_blocks_from_str(input)? {
+        if !block.trim().is_empty() {
+            vec.push(Picture::from_str(block.trim_end()).map_err(|e| e.offset(line))?);
+        }
+        line += block.split('\n').count() + 1;
+    }
+
+    return Ok(vec);
+}
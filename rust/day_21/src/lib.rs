@@ -0,0 +1,174 @@
+use aoc::budget;
+use aoc::record::{parse_records, Fields, Format, FromInput};
+use aoc::{info, Error, Solution};
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Recipe>;
+    type AnswerA = usize;
+    type AnswerB = String;
+
+    fn day(&self) -> u8 {
+        return 21;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Recipe>, Error> {
+        let recipes = parse_inputs(input)?;
+        info!("Loaded {} recipes", recipes.len());
+        return Ok(recipes);
+    }
+
+    fn part_a(&self, recipes: &Vec<Recipe>) -> Result<usize, Error> {
+        return part_a(recipes);
+    }
+
+    fn part_b(&self, recipes: &Vec<Recipe>) -> Result<String, Error> {
+        return part_b(recipes);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Recipe {
+    ingredients: HashSet<String>,
+    allergens: HashSet<String>,
+}
+
+impl FromInput for Recipe {
+    fn format() -> Format {
+        return Format::new("{ingredients} (contains {allergens})")
+            .expected("a recipe like \"<ingredients> (contains <allergens>)\"");
+    }
+
+    fn from_fields(fields: &Fields) -> Result<Recipe, Error> {
+        let ingredients: Vec<String> = fields.list("ingredients", " ")?;
+        let allergens: Vec<String> = fields.list("allergens", ", ")?;
+        return Ok(Recipe {
+            ingredients: ingredients.into_iter().collect(),
+            allergens: allergens.into_iter().collect(),
+        });
+    }
+}
+
+fn get_all_ingredients(recipes: &[Recipe]) -> HashSet<String> {
+    let mut ingredients = HashSet::new();
+    for recipe in recipes {
+        for ingredient in &recipe.ingredients {
+            ingredients.insert(ingredient.clone());
+        }
+    }
+    return ingredients;
+}
+
+fn map_allergens(recipes: &[Recipe]) -> Result<HashMap<String, String>, Error> {
+    let mut possible_ingredients: HashMap<String, HashSet<String>> = HashMap::new();
+
+    for recipe in recipes {
+        for allergen in recipe.allergens.clone() {
+            match possible_ingredients.clone().get(&allergen) {
+                Some(s) => {
+                    let new_ingredients = recipe.ingredients.intersection(s);
+                    possible_ingredients.insert(allergen, new_ingredients.cloned().collect());
+                }
+                None => {
+                    possible_ingredients.insert(allergen, recipe.ingredients.clone());
+                }
+            }
+        }
+    }
+
+    let mut allergens_map: HashMap<String, String> = HashMap::new();
+    let mut reserved: HashSet<String> = HashSet::new();
+    let total = possible_ingredients.len() as u64;
+    let mut tracker = budget::current().track("passes", Some(total));
+    loop {
+        tracker.step()?;
+        tracker.check()?;
+
+        // Every pass has to pin down an allergen, or the next one would not either.
+        let mapped = allergens_map.len();
+        for (k, v) in &possible_ingredients {
+            let candidates = v
+                .clone()
+                .difference(&reserved)
+                .cloned()
+                .collect::<HashSet<String>>();
+
+            if candidates.len() == 1 {
+                let ingredient = candidates.iter().next().unwrap().clone();
+                allergens_map.insert(k.clone(), ingredient.clone());
+                reserved.insert(ingredient);
+            }
+        }
+
+        if allergens_map.len() == possible_ingredients.len() {
+            break;
+        }
+        if allergens_map.len() == mapped {
+            return Err(Error::NoSolution(format!(
+                "{} allergens are not in exactly one ingredient",
+                possible_ingredients.len() - mapped
+            )));
+        }
+    }
+
+    return Ok(allergens_map);
+}
+
+fn part_a(recipes: &[Recipe]) -> Result<usize, Error> {
+    // Get all ingredients.
+    let ingredients = get_all_ingredients(recipes);
+
+    // Get a map of {allergen: ingredient}.
+    let allergens: HashMap<String, String> = map_allergens(recipes)?;
+
+    // Filter the ingredients that have zero.
+    let unsafe_ingredients: HashSet<String> = allergens.values().cloned().collect();
+    let safe_ingredients: HashSet<String> = ingredients
+        .difference(&unsafe_ingredients)
+        .cloned()
+        .collect();
+
+    // Count how often these ingredients occur.
+    return Ok(recipes
+        .iter()
+        .map(|r| {
+            r.ingredients
+                .intersection(&safe_ingredients)
+                .cloned()
+                .collect::<HashSet<String>>()
+                .len()
+        })
+        .sum());
+}
+
+fn part_b(recipes: &[Recipe]) -> Result<String, Error> {
+    let allergens_map = map_allergens(recipes)?;
+    let mut unsafe_ingredients: Vec<(&String, &String)> = Vec::from_iter(allergens_map.iter());
+    unsafe_ingredients.sort();
+    return Ok(unsafe_ingredients
+        .iter()
+        .map(|&t| t.1.clone())
+        .collect::<Vec<String>>()
+        .join(","));
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<Recipe>, Error> {
+    return parse_records(input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ambiguous_allergens() {
+        let recipes = parse_inputs("a b (contains x)\nb a (contains y)\n").unwrap();
+        assert_eq!(
+            map_allergens(&recipes).unwrap_err().to_string(),
+            "no solution: 2 allergens are not in exactly one ingredient"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use aoc::{parse_at, parse_blocks_from_str, Error, Solution};
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Deck, Deck);
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 22;
+    }
+
+    fn parse(&self, input: &str) -> Result<(Deck, Deck), Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, decks: &(Deck, Deck)) -> Result<usize, Error> {
+        return Ok(solve(&decks.0, &decks.1, &true));
+    }
+
+    fn part_b(&self, decks: &(Deck, Deck)) -> Result<usize, Error> {
+        return Ok(solve(&decks.0, &decks.1, &false));
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Deck {
+    player: String,
+    cards: VecDeque<usize>,
+}
+
+impl FromStr for Deck {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Deck, Self::Err> {
+        let mut input_iter = input.split('\n');
+
+        // Parse the player.
+        let header = input_iter.next().unwrap();
+        let player: String = match header.strip_suffix(':') {
+            Some(p) => p.to_string(),
+            None => return Err(Error::parse(1, 1, header, "a header like \"Player 1:\"")),
+        };
+
+        // Parse the cards.
+        let mut cards: VecDeque<usize> = VecDeque::new();
+        for (i, c) in input_iter.enumerate() {
+            if !c.is_empty() {
+                cards.push_back(parse_at(c, i + 2, 1, "a card")?);
+            }
+        }
+
+        return Ok(Deck { player, cards });
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<(Deck, Deck), Error> {
+    let mut decks = Vec::new();
+
+    // Iterate over all decks, keeping track of the line they start on.
+    let mut line = 0;
+    for block in parse_blocks_from_str(input)? {
+        if !block.trim().is_empty() {
+            decks.push(Deck::from_str(&block).map_err(|e| e.offset(line))?);
+        }
+        line += block.split('\n').count() + 1;
+    }
+
+    if decks.len() != 2 {
+        return Err(Error::parse(line, 1, "", "exactly two decks"));
+    }
+    let deck_b = decks.pop().unwrap();
+    let deck_a = decks.pop().unwrap();
+    return Ok((deck_a, deck_b));
+}
+
+fn play_game(deck_a: &Deck, deck_b: &Deck) -> Deck {
+    let mut a = deck_a.clone();
+    let mut b = deck_b.clone();
+
+    loop {
+        let card_a = a.cards.pop_front().unwrap();
+        let card_b = b.cards.pop_front().unwrap();
+
+        if card_a > card_b {
+            a.cards.push_back(card_a);
+            a.cards.push_back(card_b);
+        } else if card_a < card_b {
+            b.cards.push_back(card_b);
+            b.cards.push_back(card_a);
+        }
+
+        if a.cards.is_empty() {
+            return b;
+        }
+
+        if b.cards.is_empty() {
+            return a;
+        }
+    }
+}
+
+fn stringify_state(deck_a: &Deck, deck_b: &Deck) -> String {
+    let mut state = "".to_string();
+
+    state += &deck_a.player;
+
+    let mut cards = deck_a.cards.clone();
+    while let Some(card) = cards.pop_front() {
+        state += ",";
+        state += &card.to_string();
+    }
+
+    state += &deck_b.player;
+
+    let mut cards = deck_b.cards.clone();
+    while let Some(card) = cards.pop_front() {
+        state += ",";
+        state += &card.to_string();
+    }
+
+    return state;
+}
+
+fn play_recursive_game(deck_a: &Deck, deck_b: &Deck) -> Deck {
+    let mut a = deck_a.clone();
+    let mut b = deck_b.clone();
+    let mut h = HashSet::new();
+
+    loop {
+        // Check for instant player 1 win if we are in a loop.
+        let state = stringify_state(&a, &b);
+        if h.contains(&state) {
+            return a.clone();
+        }
+        h.insert(state);
+
+        // Draw cards.
+        let card_a = a.cards.pop_front().unwrap();
+        let card_b = b.cards.pop_front().unwrap();
+
+        // Determine the winner.
+        let winner: String;
+        if (card_a <= a.cards.len()) && (card_b <= b.cards.len()) {
+            let mut a_trunc = a.clone();
+            for _ in card_a..a.cards.len() {
+                a_trunc.cards.pop_back();
+            }
+
+            let mut b_trunc = b.clone();
+            for _ in card_b..b.cards.len() {
+                b_trunc.cards.pop_back();
+            }
+
+            winner = play_recursive_game(&a_trunc, &b_trunc).player
+        } else {
+            if card_a > card_b {
+                winner = a.player.clone();
+            } else {
+                winner = b.player.clone();
+            }
+        }
+
+        // Assign cards.
+        if winner == a.player {
+            a.cards.push_back(card_a);
+            a.cards.push_back(card_b);
+        } else {
+            b.cards.push_back(card_b);
+            b.cards.push_back(card_a);
+        }
+
+        // Check for game end.
+        if a.cards.is_empty() {
+            return b.clone();
+        }
+        if b.cards.is_empty() {
+            return a.clone();
+        }
+    }
+}
+
+fn solve(deck_a: &Deck, deck_b: &Deck, part_a: &bool) -> usize {
+    let mut winner: Deck;
+    if *part_a {
+        winner = play_game(deck_a, deck_b);
+    } else {
+        winner = play_recursive_game(deck_a, deck_b);
+    }
+
+    let mut counter = 0;
+    while let Some(card) = winner.cards.pop_front() {
+        let multiplier = winner.cards.len() + 1;
+        counter += card * multiplier;
+    }
+    return counter;
+}
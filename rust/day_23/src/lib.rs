@@ -0,0 +1,293 @@
+use aoc::budget;
+use aoc::params::{parse_param, unknown_param};
+use aoc::{parse_lines_from_str, Error, Solution};
+use std::collections::VecDeque;
+
+/// Play `moves_a` moves with the labeled cups, then `moves_b` moves with `cups` cups.
+pub struct Day23 {
+    pub moves_a: usize,
+    pub moves_b: usize,
+    pub cups: usize,
+}
+
+impl Default for Day23 {
+    fn default() -> Day23 {
+        return Day23 {
+            moves_a: 100,
+            moves_b: 10000000,
+            cups: 1000000,
+        };
+    }
+}
+
+impl Solution for Day23 {
+    type Input = VecDeque<usize>;
+    type AnswerA = String;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 23;
+    }
+
+    fn parse(&self, input: &str) -> Result<VecDeque<usize>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, start: &VecDeque<usize>) -> Result<String, Error> {
+        return Ok(get_result_a(play_game(start, self.moves_a)?));
+    }
+
+    fn part_b(&self, start: &VecDeque<usize>) -> Result<usize, Error> {
+        let padded_queue = pad_queue(start, self.cups);
+        return Ok(get_result_b(&play_game(&padded_queue, self.moves_b)?));
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return vec![
+            ("moves_a", self.moves_a.to_string()),
+            ("moves_b", self.moves_b.to_string()),
+            ("cups", self.cups.to_string()),
+        ];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "moves_a" => self.moves_a = parse_param(name, value, "a number of moves")?,
+            "moves_b" => self.moves_b = parse_param(name, value, "a number of moves")?,
+            "cups" => self.cups = parse_param(name, value, "a number of cups")?,
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+struct Cups {
+    cups: Vec<usize>,
+}
+
+impl Cups {
+    fn from_dequeue(dequeue: &VecDeque<usize>) -> Cups {
+        // A ghetto linked list with quick access to each item.
+        let len = dequeue.len();
+        let mut cups = vec![0; len + 1];
+
+        for i in 0..len {
+            let cup = *dequeue.get(i).unwrap();
+            let next = *dequeue.get((i + 1) % len).unwrap();
+            cups[cup] = next;
+        }
+        return Cups { cups };
+    }
+
+    fn get_after(&self, value: usize) -> usize {
+        return self.cups[value];
+    }
+
+    fn remove_after(&mut self, value: usize) -> usize {
+        let a = self.cups[value];
+        let b = self.cups[a];
+
+        // Update the record before.
+        self.cups[value] = b;
+
+        return a;
+    }
+
+    fn remove_three_after(&mut self, value: usize) -> (usize, usize, usize) {
+        let a = self.cups[value];
+        let b = self.cups[a];
+        let c = self.cups[b];
+        let d = self.cups[c];
+
+        // Update the record before.
+        self.cups[value] = d;
+
+        return (a, b, c);
+    }
+
+    fn insert_after(&mut self, after: usize, value: usize) {
+        let a = self.cups[after];
+
+        // Update the record before.
+        self.cups[after] = value;
+        self.cups[value] = a;
+    }
+}
+
+fn parse_inputs(input: &str) -> Result<VecDeque<usize>, Error> {
+    let mut queue = VecDeque::new();
+    let lines = parse_lines_from_str(input)?;
+    let input_string = lines.first().map(|s| s.as_str()).unwrap_or("");
+    if input_string.is_empty() {
+        return Err(Error::parse(1, 1, "", "a row of cup labels"));
+    }
+
+    // The labels have to be 1 up to the number of cups, each used once, or the crab gets lost.
+    let count = input_string.chars().count();
+    let mut seen = vec![false; count + 1];
+    for (i, c) in input_string.chars().enumerate() {
+        let label = match c.to_digit(10) {
+            Some(d) if d > 0 && d as usize <= count => d as usize,
+            _ => {
+                return Err(Error::parse(
+                    1,
+                    i + 1,
+                    &c.to_string(),
+                    &format!("a cup label from 1 to {}", count.min(9)),
+                ))
+            }
+        };
+        if seen[label] {
+            return Err(Error::parse(
+                1,
+                i + 1,
+                &c.to_string(),
+                "a cup label that is not taken yet",
+            ));
+        }
+        seen[label] = true;
+        queue.push_back(label);
+    }
+
+    // The crab picks up three cups and needs a destination besides the current cup.
+    if count < 5 {
+        return Err(Error::parse(1, count + 1, "", "at least 5 cups"));
+    }
+    return Ok(queue);
+}
+
+fn play_game(start: &VecDeque<usize>, moves: usize) -> Result<Cups, Error> {
+    let mut tracker = budget::current().track("moves", Some(moves as u64));
+    let mut cups = Cups::from_dequeue(start);
+    let max_cup = *start.iter().max().unwrap();
+
+    // Now we play cups!
+    let mut current_cup = *start.front().unwrap();
+    for _ in 0..moves {
+        tracker.step()?;
+
+        // The crab picks up the three cups that are immediately clockwise of the current cup. They
+        // are removed from the circle; cup spacing is adjusted as necessary to maintain the circle.
+        let picked_up = cups.remove_three_after(current_cup);
+
+        // The crab selects a destination cup: the cup with a label equal to the current cup's label
+        // minus one. If this would select one of the cups that was just picked up, the crab will
+        // keep subtracting one until it finds a cup that wasn't just picked up. If at any point in
+        // this process the value goes below the lowest value on any cup's label, it wraps around to
+        // the highest value on any cup's label instead.
+        let mut destination_cup = current_cup;
+        while (destination_cup == current_cup)
+            || (picked_up.0 == destination_cup)
+            || (picked_up.1 == destination_cup)
+            || (picked_up.2 == destination_cup)
+        {
+            destination_cup = match destination_cup {
+                1 => max_cup,
+                _ => destination_cup - 1,
+            };
+        }
+
+        // The crab places the cups it just picked up so that they are immediately clockwise of the
+        // destination cup. They keep the same order as when they were picked up.
+        cups.insert_after(destination_cup, picked_up.2);
+        cups.insert_after(destination_cup, picked_up.1);
+        cups.insert_after(destination_cup, picked_up.0);
+
+        // The crab selects a new current cup: the cup which is immediately clockwise of the current
+        // cup.
+        current_cup = cups.get_after(current_cup);
+    }
+
+    return Ok(cups);
+}
+
+fn pad_queue(input: &VecDeque<usize>, size: usize) -> VecDeque<usize> {
+    let mut result = input.clone();
+    for i in input.len()..size {
+        result.push_back(i + 1);
+    }
+    return result;
+}
+
+fn get_result_a(mut cups: Cups) -> String {
+    let mut result = "".to_string();
+
+    let mut last = 1;
+    for _ in 0..(cups.cups.len() - 2) {
+        last = cups.remove_after(last);
+        result += &last.to_string();
+    }
+    return result;
+}
+
+fn get_result_b(cups: &Cups) -> usize {
+    let first = cups.get_after(1);
+    let second = cups.get_after(first);
+
+    return first * second;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Play with the cups in a row that is rotated to keep the current cup in front.
+    fn play_naive(start: &[usize], moves: usize) -> String {
+        let mut cups = start.to_vec();
+        let max_cup = *cups.iter().max().unwrap();
+        for _ in 0..moves {
+            let current = cups[0];
+            let picked: Vec<usize> = cups.drain(1..4).collect();
+            let mut destination = current;
+            loop {
+                destination = if destination == 1 {
+                    max_cup
+                } else {
+                    destination - 1
+                };
+                if !picked.contains(&destination) {
+                    break;
+                }
+            }
+            let at = cups.iter().position(|c| *c == destination).unwrap() + 1;
+            cups.splice(at..at, picked);
+            cups.rotate_left(1);
+        }
+
+        let one = cups.iter().position(|c| *c == 1).unwrap();
+        cups.rotate_left(one);
+        return cups[1..].iter().map(|c| c.to_string()).collect();
+    }
+
+    fn cups() -> impl Strategy<Value = Vec<usize>> {
+        return (5..=9usize)
+            .prop_flat_map(|n| Just((1..=n).collect::<Vec<usize>>()).prop_shuffle());
+    }
+
+    #[test]
+    fn invalid_cups() {
+        let error = |input: &str| parse_inputs(input).unwrap_err().to_string();
+        assert_eq!(
+            error("19"),
+            "line 1, column 2: unexpected \"9\", expected a cup label from 1 to 2"
+        );
+        assert_eq!(
+            error("12324"),
+            "line 1, column 4: unexpected \"2\", expected a cup label that is not taken yet"
+        );
+        assert_eq!(error("123"), "line 1, column 4: expected at least 5 cups");
+        assert_eq!(
+            parse_inputs("32415").unwrap(),
+            VecDeque::from(vec![3, 2, 4, 1, 5])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn games_agree(start in cups(), moves in 0..100usize) {
+            let queue: VecDeque<usize> = start.iter().copied().collect();
+            prop_assert_eq!(get_result_a(play_game(&queue, moves).unwrap()), play_naive(&start, moves));
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use aoc::automaton::{Automaton, HexGrid, Rule, SparseAutomaton};
+use aoc::params::{parse_param, unknown_param};
+use aoc::{parse_lines_from_str, Error, Solution};
+use std::collections::HashMap;
+
+/// Flip the tiles, then let them flip each other for a number of `days`.
+pub struct Day24 {
+    pub days: usize,
+}
+
+impl Default for Day24 {
+    fn default() -> Day24 {
+        return Day24 { days: 100 };
+    }
+}
+
+impl Solution for Day24 {
+    type Input = Vec<(isize, isize)>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn day(&self) -> u8 {
+        return 24;
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<(isize, isize)>, Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, inputs: &Vec<(isize, isize)>) -> Result<usize, Error> {
+        return Ok(part_a(inputs));
+    }
+
+    fn part_b(&self, inputs: &Vec<(isize, isize)>) -> Result<usize, Error> {
+        return Ok(part_b(inputs, self.days));
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return vec![("days", self.days.to_string())];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "days" => self.days = parse_param(name, value, "a number of days")?,
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+fn resolve_tile(input: &str) -> Result<(isize, isize), Error> {
+    let mut iter = input.chars().enumerate();
+    let mut x = 0;
+    let mut y = 0;
+
+    while let Some((i, a)) = iter.next() {
+        if a == 'e' {
+            x += 1;
+        } else if a == 'w' {
+            x -= 1;
+        } else {
+            let b = iter.next().map(|t| t.1).unwrap_or(' ');
+            if (a == 'n') && (b == 'e') {
+                y += 1;
+            } else if (a == 'n') && (b == 'w') {
+                y += 1;
+                x -= 1;
+            } else if (a == 's') && (b == 'e') {
+                y -= 1;
+                x += 1;
+            } else if (a == 's') && (b == 'w') {
+                y -= 1;
+            } else {
+                let text: String = input.chars().skip(i).take(2).collect();
+                return Err(Error::parse(
+                    1,
+                    i + 1,
+                    &text,
+                    "one of e, se, sw, w, nw or ne",
+                ));
+            }
+        }
+    }
+
+    return Ok((x, y));
+}
+
+fn parse_inputs(input: &str) -> Result<Vec<(isize, isize)>, Error> {
+    let mut vec = Vec::new();
+
+    for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+        vec.push(resolve_tile(line).map_err(|e| e.offset(i))?);
+    }
+
+    return Ok(vec);
+}
+
+fn get_tiles(inputs: &[(isize, isize)]) -> HashMap<(isize, isize), bool> {
+    let mut tiles: HashMap<(isize, isize), bool> = HashMap::new();
+
+    for tile in inputs {
+        let tile = *tile;
+        let tiles_clone = tiles.clone();
+        match tiles_clone.get(&tile) {
+            Some(b) => tiles.insert(tile, !*b),
+            None => tiles.insert(tile, true),
+        };
+    }
+    return tiles;
+}
+
+fn part_a(inputs: &[(isize, isize)]) -> usize {
+    return get_tiles(inputs).iter().filter(|&t| *t.1).count();
+}
+
+fn part_b(inputs: &[(isize, isize)], days: usize) -> usize {
+    let black = get_tiles(inputs).into_iter().filter(|t| t.1).map(|t| t.0);
+
+    // Black tiles with one or two black neighbours stay, white ones with two turn black.
+    let mut floor: SparseAutomaton<HexGrid> =
+        Automaton::new(HexGrid, Rule::new(&[2], &[1, 2]), black);
+    floor.run(days);
+
+    return floor.population();
+}
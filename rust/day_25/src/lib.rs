@@ -0,0 +1,113 @@
+use aoc::math::{discrete_log, mod_pow};
+use aoc::params::{parse_param, unknown_param};
+use aoc::{parse_at, parse_lines_from_str, Error, Solution};
+
+/// Break the handshake, which transforms the `subject` number modulo `modulus`.
+pub struct Day25 {
+    pub modulus: usize,
+    pub subject: usize,
+}
+
+impl Default for Day25 {
+    fn default() -> Day25 {
+        return Day25 {
+            modulus: CHRISTMAS,
+            subject: SUBJECT_NUMBER,
+        };
+    }
+}
+
+impl Solution for Day25 {
+    type Input = (usize, usize);
+    type AnswerA = usize;
+    type AnswerB = &'static str;
+
+    fn day(&self) -> u8 {
+        return 25;
+    }
+
+    fn parse(&self, input: &str) -> Result<(usize, usize), Error> {
+        return parse_inputs(input);
+    }
+
+    fn part_a(&self, keys: &(usize, usize)) -> Result<usize, Error> {
+        return get_encryption_key(&keys.0, &keys.1, self.subject, self.modulus);
+    }
+
+    fn part_b(&self, _keys: &(usize, usize)) -> Result<&'static str, Error> {
+        // There is no puzzle for part B, it is handed out for finishing all other stars.
+        return Ok("Merry Christmas!");
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        return vec![
+            ("modulus", self.modulus.to_string()),
+            ("subject", self.subject.to_string()),
+        ];
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        match name {
+            "modulus" => {
+                let expected = "a modulus from 2 to 2^40";
+                self.modulus = match parse_param(name, value, expected)? {
+                    m if (2..=MAX_MODULUS).contains(&m) => m,
+                    _ => {
+                        return Err(Error::Param(format!(
+                            "{} = {}, expected {}",
+                            name, value, expected
+                        )))
+                    }
+                };
+            }
+            "subject" => self.subject = parse_param(name, value, "a number")?,
+            _ => return Err(unknown_param(name, &self.params())),
+        }
+        return Ok(());
+    }
+}
+
+const CHRISTMAS: usize = 20201227;
+const SUBJECT_NUMBER: usize = 7;
+/// The discrete logarithm keeps a table of the square root of the modulus in entries, which is
+/// about a million here.
+const MAX_MODULUS: usize = 1 << 40;
+
+fn get_encryption_key(
+    pk_a: &usize,
+    pk_b: &usize,
+    subject: usize,
+    modulus: usize,
+) -> Result<usize, Error> {
+    // The loop size is the discrete logarithm of the public key.
+    let ls_a = discrete_log(subject as i128, *pk_a as i128, modulus as i128)
+        .ok_or_else(|| Error::NoSolution(format!("no loop size gives public key {}", pk_a)))?;
+    return Ok(mod_pow(*pk_b as i128, ls_a as u128, modulus as i128) as usize);
+}
+
+fn parse_inputs(input: &str) -> Result<(usize, usize), Error> {
+    let inputs = parse_lines_from_str(input)?;
+    let mut inputs_iter = inputs.iter().map(|s| s.as_str());
+
+    let pk_a: usize = parse_at(inputs_iter.next().unwrap_or(""), 1, 1, "a public key")?;
+    let pk_b: usize = parse_at(inputs_iter.next().unwrap_or(""), 2, 1, "a public key")?;
+    return Ok((pk_a, pk_b));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modulus_param() {
+        let mut day = Day25::default();
+        assert!(day.set_param("modulus", "1099511627776").is_ok());
+        assert_eq!(
+            day.set_param("modulus", "1099511627777")
+                .unwrap_err()
+                .to_string(),
+            "bad parameter: modulus = 1099511627777, expected a modulus from 2 to 2^40"
+        );
+        assert!(day.set_param("modulus", "1").is_err());
+    }
+}
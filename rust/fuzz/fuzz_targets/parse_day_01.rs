@@ -0,0 +1,7 @@
+#![no_main]
+use aoc::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = day_01::Day01::default().parse(&String::from_utf8_lossy(data));
+});
@@ -0,0 +1,7 @@
+#![no_main]
+use aoc::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = day_02::Day02.parse(&String::from_utf8_lossy(data));
+});
@@ -0,0 +1,238 @@
+use aoc::budget;
+use aoc::{parse_at, parse_lines_from_str, Error, Part, Runnable};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A timed step of solving a day, parsing is timed separately from the parts.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(p) => f.pad(&p.to_string()),
+        }
+    }
+}
+
+impl Stage {
+    fn from_name(name: &str) -> Option<Stage> {
+        return match name {
+            "parse" => Some(Stage::Parse),
+            "A" => Some(Stage::Part(Part::A)),
+            "B" => Some(Stage::Part(Part::B)),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarize the timings of repeated runs, there has to be at least one.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        return Stats {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        };
+    }
+}
+
+/// Format a duration with three significant digits in a readable unit.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+
+    let decimals = if value < 10.0 {
+        2
+    } else if value < 100.0 {
+        1
+    } else {
+        0
+    };
+    return format!("{:.*}{}", decimals, value, unit);
+}
+
+/// Time parsing and the given parts of a day over a number of runs.
+pub fn measure(
+    solution: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Vec<(Stage, Stats)>, Error> {
+    let mut stages = vec![Stage::Parse];
+    stages.extend(parts.iter().map(|p| Stage::Part(*p)));
+    let mut samples = vec![Vec::new(); stages.len()];
+    // Every run of a part gets the whole timeout.
+    let budget = budget::current();
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = solution.parse(input)?;
+        samples[0].push(start.elapsed());
+
+        for (i, part) in parts.iter().enumerate() {
+            let start = Instant::now();
+            budget::with(budget.restart(), || solution.run(parsed.as_ref(), *part))?;
+            samples[i + 1].push(start.elapsed());
+        }
+    }
+
+    return Ok(stages
+        .into_iter()
+        .zip(samples)
+        .map(|(stage, s)| (stage, Stats::from_samples(&s)))
+        .collect());
+}
+
+/// Median timings of an earlier benchmark, stored as lines like `15 B 1234567` in nanoseconds.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl FromStr for Baseline {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Baseline, Self::Err> {
+        let mut medians = BTreeMap::new();
+
+        for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+            // Skip blank lines and comments.
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.trim_end().split(' ').collect();
+            if fields.len() != 3 {
+                return Err(Error::parse(
+                    i + 1,
+                    1,
+                    line,
+                    "a timing like \"<day> <parse|A|B> <nanoseconds>\"",
+                ));
+            }
+            let day: u8 = parse_at(fields[0], i + 1, 1, "a day")?;
+            let column = fields[0].len() + 2;
+            let stage = Stage::from_name(fields[1])
+                .ok_or_else(|| Error::parse(i + 1, column, fields[1], "one of parse, A or B"))?;
+            let column = column + fields[1].len() + 1;
+            let nanos: u64 = parse_at(fields[2], i + 1, column, "a number of nanoseconds")?;
+            medians.insert((day, stage), Duration::from_nanos(nanos));
+        }
+
+        return Ok(Baseline { medians });
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for ((day, stage), median) in &self.medians {
+            writeln!(f, "{} {} {}", day, stage, median.as_nanos())?;
+        }
+        return Ok(());
+    }
+}
+
+impl Baseline {
+    pub fn from_file(path: &Path) -> Result<Baseline, Error> {
+        return Baseline::from_str(&fs::read_to_string(path)?);
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        return self.medians.get(&(day, stage)).copied();
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+}
+
+/// The relative change of a median against the baseline, in percent.
+pub fn change(baseline: Duration, median: Duration) -> f64 {
+    let baseline = baseline.as_nanos().max(1) as f64;
+    return (median.as_nanos() as f64 - baseline) / baseline * 100.0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Stats::from_samples(&[ms(5), ms(1), ms(3)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(&[ms(4), ms(1), ms(2), ms(9)]).median,
+            ms(3)
+        );
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(12345)), "12.3s");
+    }
+
+    #[test]
+    fn baseline() {
+        let mut baseline = Baseline::default();
+        baseline.insert(15, Stage::Part(Part::B), Duration::from_nanos(1234567));
+        baseline.insert(15, Stage::Parse, Duration::from_nanos(800));
+
+        let text = baseline.to_string();
+        assert_eq!(text, "15 parse 800\n15 B 1234567\n");
+        assert_eq!(Baseline::from_str(&text).unwrap(), baseline);
+        assert_eq!(
+            Baseline::from_str("15 C 100").unwrap_err().to_string(),
+            "line 1, column 4: unexpected \"C\", expected one of parse, A or B"
+        );
+    }
+
+    #[test]
+    fn changes() {
+        let ms = Duration::from_millis;
+        assert_eq!(change(ms(100), ms(110)).round(), 10.0);
+        assert_eq!(change(ms(100), ms(50)).round(), -50.0);
+    }
+}
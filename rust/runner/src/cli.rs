@@ -0,0 +1,422 @@
+use aoc::params::split_assignment;
+use aoc::Part;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "Usage: aoc <command> [options]
+
+Commands:
+    run <day>... | all    Solve the given days, or every registered day
+    check <day>... | all  Solve every input set of the given days and compare the answers
+    bench <day>... | all  Time parsing and both parts of the given days over repeated runs
+    generate <day>... | all
+                          Write a random input with known answers for the given days
+    list                  List the registered days with their parameters
+    help                  Show this message
+
+Options:
+    --part <a|b>          Only solve one part
+    --input <path>        Read the input from a file, for a single day
+    --inputs <dir>        Look for inputs as <dir>/day_NN.txt (default: inputs)
+    --json                Print a JSON object per part of run, one per line
+    --answers <path>      Expected answers for check and generate (default: <inputs>/answers.txt)
+    --runs <n>            Number of runs for bench (default: 10)
+    --baseline <path>     Compare the bench medians against an earlier --save
+    --save <path>         Save the bench medians as a baseline
+    --threshold <pct>     Slowdown against the baseline counted as a regression (default: 10)
+    --seed <n>            Seed of the generated inputs, written as day_NN.seed-<n>.txt (default: 1)
+    --size <n>            Size of the generated inputs, its meaning depends on the day
+    --param <name>=<value>
+                          Override a puzzle parameter of the days that have it, e.g. preamble=5
+    --params <path>       Read parameters from a file of lines like \"9 preamble 5\"
+    --timeout <secs>      Stop a solver after this many seconds in its long running loops
+    --max-steps <n>       Stop a long running loop of a solver after this many steps
+    --jobs <n>            Threads for solving days at once and for the searches within days (default: 1)
+    -v, -q                Raise or lower the verbosity";
+
+/// Which days to solve, `All` skips days without an input instead of failing.
+#[derive(Debug, PartialEq)]
+pub enum Days {
+    All,
+    Some(Vec<u8>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Run {
+    pub days: Days,
+    pub parts: Vec<Part>,
+    pub input: Option<PathBuf>,
+    pub input_dir: PathBuf,
+    pub json: bool,
+    pub answers: Option<PathBuf>,
+    pub runs: usize,
+    pub baseline: Option<PathBuf>,
+    pub save: Option<PathBuf>,
+    pub threshold: f64,
+    pub seed: u64,
+    pub size: Option<usize>,
+    pub params: Vec<(String, String)>,
+    pub params_file: Option<PathBuf>,
+    pub timeout: Option<Duration>,
+    pub max_steps: Option<u64>,
+    pub jobs: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(Run),
+    Check(Run),
+    Bench(Run),
+    Generate(Run),
+    List,
+    Help,
+}
+
+pub fn parse_part(value: &str) -> Result<Part, String> {
+    return match value.to_lowercase().as_str() {
+        "a" | "1" => Ok(Part::A),
+        "b" | "2" => Ok(Part::B),
+        _ => Err(format!("unknown part {:?}, expected a or b", value)),
+    };
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    return match value.parse::<u8>() {
+        Ok(d) if (1..=25).contains(&d) => Ok(d),
+        _ => Err(format!("unknown day {:?}, expected 1 to 25 or all", value)),
+    };
+}
+
+/// Parse the arguments following the binary name.
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args
+        .into_iter()
+        // The verbosity flags are picked up by `aoc::log::init`.
+        .filter(|a| !(a.len() > 1 && a.starts_with('-') && a[1..].chars().all(|c| c == 'v')))
+        .filter(|a| !matches!(a.as_str(), "-q" | "--quiet" | "--verbose"));
+
+    let command = match args.next() {
+        Some(c) => c,
+        None => return Ok(Command::Help),
+    };
+    match command.as_str() {
+        "run" | "check" | "bench" | "generate" => {}
+        "list" => return Ok(Command::List),
+        "help" | "-h" | "--help" => return Ok(Command::Help),
+        _ => return Err(format!("unknown command {:?}", command)),
+    }
+
+    let mut all = false;
+    let mut days = Vec::new();
+    let mut parts = vec![Part::A, Part::B];
+    let mut input = None;
+    let mut input_dir = PathBuf::from("inputs");
+    let mut json = false;
+    let mut answers = None;
+    let mut runs = 10;
+    let mut baseline = None;
+    let mut save = None;
+    let mut threshold = 10.0;
+    let mut seed = 1;
+    let mut size = None;
+    let mut params = Vec::new();
+    let mut params_file = None;
+    let mut timeout = None;
+    let mut max_steps = None;
+    let mut jobs = 1;
+
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .ok_or_else(|| format!("missing value for {}", flag))
+        };
+        match arg.as_str() {
+            "--part" => parts = vec![parse_part(&value(&arg)?)?],
+            "--input" => input = Some(PathBuf::from(value(&arg)?)),
+            "--inputs" => input_dir = PathBuf::from(value(&arg)?),
+            "--json" if command == "run" => json = true,
+            "--answers" if command == "check" || command == "generate" => {
+                answers = Some(PathBuf::from(value(&arg)?))
+            }
+            "--runs" if command == "bench" => {
+                runs = match value(&arg)?.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err("--runs expects a positive number".to_string()),
+                }
+            }
+            "--baseline" if command == "bench" => baseline = Some(PathBuf::from(value(&arg)?)),
+            "--save" if command == "bench" => save = Some(PathBuf::from(value(&arg)?)),
+            "--threshold" if command == "bench" => {
+                threshold = value(&arg)?
+                    .parse()
+                    .map_err(|_| "--threshold expects a percentage".to_string())?
+            }
+            "--seed" if command == "generate" => {
+                seed = value(&arg)?
+                    .parse()
+                    .map_err(|_| "--seed expects a number".to_string())?
+            }
+            "--size" if command == "generate" => {
+                size = match value(&arg)?.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err("--size expects a positive number".to_string()),
+                }
+            }
+            // Generated answers come from the puzzles as described.
+            "--param" if command != "generate" => {
+                params.push(split_assignment(&value(&arg)?).map_err(|e| e.to_string())?)
+            }
+            "--params" if command != "generate" => params_file = Some(PathBuf::from(value(&arg)?)),
+            "--timeout" if command != "generate" => {
+                timeout = match value(&arg)?.parse::<f64>() {
+                    Ok(s) if s > 0.0 && s.is_finite() => Some(Duration::from_secs_f64(s)),
+                    _ => return Err("--timeout expects a positive number of seconds".to_string()),
+                }
+            }
+            "--max-steps" if command != "generate" => {
+                max_steps = Some(
+                    value(&arg)?
+                        .parse()
+                        .map_err(|_| "--max-steps expects a number".to_string())?,
+                )
+            }
+            "--jobs" if command == "run" || command == "check" => {
+                jobs = match value(&arg)?.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err("--jobs expects a positive number".to_string()),
+                }
+            }
+            "all" => all = true,
+            _ if arg.starts_with('-') => return Err(format!("unknown option {:?}", arg)),
+            _ => days.push(parse_day(&arg)?),
+        }
+    }
+
+    let days = match (all, days.is_empty()) {
+        (true, true) => Days::All,
+        (false, false) => Days::Some(days),
+        (true, false) => return Err("give either day numbers or all, not both".to_string()),
+        (false, true) => return Err("missing a day to run".to_string()),
+    };
+    if input.is_some() && !matches!(&days, Days::Some(d) if d.len() == 1) {
+        return Err("--input can only be used with a single day".to_string());
+    }
+
+    let run = Run {
+        days,
+        parts,
+        input,
+        input_dir,
+        json,
+        answers,
+        runs,
+        baseline,
+        save,
+        threshold,
+        seed,
+        size,
+        params,
+        params_file,
+        timeout,
+        max_steps,
+        jobs,
+    };
+    return match command.as_str() {
+        "check" => Ok(Command::Check(run)),
+        "bench" => Ok(Command::Bench(run)),
+        "generate" => Ok(Command::Generate(run)),
+        _ => Ok(Command::Run(run)),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        return line.split_whitespace().map(|s| s.to_string()).collect();
+    }
+
+    #[test]
+    fn run_one_part() {
+        assert_eq!(
+            parse(args("run 10 --part b -v")),
+            Ok(Command::Run(Run {
+                days: Days::Some(vec![10]),
+                parts: vec![Part::B],
+                input: None,
+                input_dir: PathBuf::from("inputs"),
+                json: false,
+                answers: None,
+                runs: 10,
+                baseline: None,
+                save: None,
+                threshold: 10.0,
+                seed: 1,
+                size: None,
+                params: Vec::new(),
+                params_file: None,
+                timeout: None,
+                max_steps: None,
+                jobs: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn run_all() {
+        assert_eq!(
+            parse(args("run all --inputs data --json")),
+            Ok(Command::Run(Run {
+                days: Days::All,
+                parts: vec![Part::A, Part::B],
+                input: None,
+                input_dir: PathBuf::from("data"),
+                json: true,
+                answers: None,
+                runs: 10,
+                baseline: None,
+                save: None,
+                threshold: 10.0,
+                seed: 1,
+                size: None,
+                params: Vec::new(),
+                params_file: None,
+                timeout: None,
+                max_steps: None,
+                jobs: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(
+            parse(args(
+                "check 21 22 --answers answers.txt --max-steps 1000 --jobs 4"
+            )),
+            Ok(Command::Check(Run {
+                days: Days::Some(vec![21, 22]),
+                parts: vec![Part::A, Part::B],
+                input: None,
+                input_dir: PathBuf::from("inputs"),
+                json: false,
+                answers: Some(PathBuf::from("answers.txt")),
+                runs: 10,
+                baseline: None,
+                save: None,
+                threshold: 10.0,
+                seed: 1,
+                size: None,
+                params: Vec::new(),
+                params_file: None,
+                timeout: None,
+                max_steps: Some(1000),
+                jobs: 4,
+            }))
+        );
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse(args(
+                "bench 15 --part b --runs 3 --baseline base.txt --threshold 5 --timeout 2.5"
+            )),
+            Ok(Command::Bench(Run {
+                days: Days::Some(vec![15]),
+                parts: vec![Part::B],
+                input: None,
+                input_dir: PathBuf::from("inputs"),
+                json: false,
+                answers: None,
+                runs: 3,
+                baseline: Some(PathBuf::from("base.txt")),
+                save: None,
+                threshold: 5.0,
+                seed: 1,
+                size: None,
+                params: Vec::new(),
+                params_file: None,
+                timeout: Some(Duration::from_millis(2500)),
+                max_steps: None,
+                jobs: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn generate() {
+        assert_eq!(
+            parse(args("generate 1 9 --seed 42 --size 50")),
+            Ok(Command::Generate(Run {
+                days: Days::Some(vec![1, 9]),
+                parts: vec![Part::A, Part::B],
+                input: None,
+                input_dir: PathBuf::from("inputs"),
+                json: false,
+                answers: None,
+                runs: 10,
+                baseline: None,
+                save: None,
+                threshold: 10.0,
+                seed: 42,
+                size: Some(50),
+                params: Vec::new(),
+                params_file: None,
+                timeout: None,
+                max_steps: None,
+                jobs: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn params() {
+        assert_eq!(
+            parse(args(
+                "run 9 23 --param preamble=5 --param cups=20 --params params.txt"
+            )),
+            Ok(Command::Run(Run {
+                days: Days::Some(vec![9, 23]),
+                parts: vec![Part::A, Part::B],
+                input: None,
+                input_dir: PathBuf::from("inputs"),
+                json: false,
+                answers: None,
+                runs: 10,
+                baseline: None,
+                save: None,
+                threshold: 10.0,
+                seed: 1,
+                size: None,
+                params: vec![
+                    ("preamble".to_string(), "5".to_string()),
+                    ("cups".to_string(), "20".to_string())
+                ],
+                params_file: Some(PathBuf::from("params.txt")),
+                timeout: None,
+                max_steps: None,
+                jobs: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse(args("run 26")).is_err());
+        assert!(parse(args("run all --input day_14.txt")).is_err());
+        assert!(parse(args("run 14 --part")).is_err());
+        assert!(parse(args("solve 14")).is_err());
+        assert!(parse(args("run 14 --answers answers.txt")).is_err());
+        assert!(parse(args("bench 14 --runs 0")).is_err());
+        assert!(parse(args("run 14 --seed 3")).is_err());
+        assert!(parse(args("generate 14 --size 0")).is_err());
+        assert!(parse(args("run 9 --param preamble")).is_err());
+        assert!(parse(args("generate 9 --param preamble=5")).is_err());
+        assert!(parse(args("run 15 --timeout 0")).is_err());
+        assert!(parse(args("run 15 --max-steps many")).is_err());
+        assert!(parse(args("run all --jobs 0")).is_err());
+        assert!(parse(args("bench all --jobs 4")).is_err());
+    }
+}
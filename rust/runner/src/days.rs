@@ -0,0 +1,32 @@
+use aoc::Registry;
+
+/// All solved days, in order.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register(day_01::Day01::default());
+    registry.register(day_02::Day02);
+    registry.register(day_03::Day03::default());
+    registry.register(day_04::Day04);
+    registry.register(day_05::Day05);
+    registry.register(day_06::Day06);
+    registry.register(day_07::Day07::default());
+    registry.register(day_08::Day08);
+    registry.register(day_09::Day09::default());
+    registry.register(day_10::Day10);
+    registry.register(day_11::Day11);
+    registry.register(day_12::Day12);
+    registry.register(day_13::Day13);
+    registry.register(day_14::Day14);
+    registry.register(day_15::Day15::default());
+    registry.register(day_16::Day16);
+    registry.register(day_17::Day17::default());
+    registry.register(day_18::Day18);
+    registry.register(day_19::Day19);
+    registry.register(day_20::Day20);
+    registry.register(day_21::Day21);
+    registry.register(day_22::Day22);
+    registry.register(day_23::Day23::default());
+    registry.register(day_24::Day24::default());
+    registry.register(day_25::Day25::default());
+    return registry;
+}
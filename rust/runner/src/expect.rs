@@ -0,0 +1,151 @@
+use crate::cli::parse_part;
+use aoc::{parse_at, parse_lines_from_str, Error, Part};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Known answers keyed on day, part and input set, read from lines like `14 a input 12345`.
+#[derive(Debug, Default)]
+pub struct Expectations {
+    answers: HashMap<(u8, Part, String), String>,
+}
+
+/// The outcome of comparing an answer against the expectations.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+impl FromStr for Expectations {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Expectations, Self::Err> {
+        let mut answers = HashMap::new();
+
+        for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+            let line = line.trim_end();
+            // Skip blank lines and comments.
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // The answer is the remainder of the line, so it may contain spaces.
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            if fields.len() < 4 || fields[3].is_empty() {
+                return Err(Error::parse(
+                    i + 1,
+                    1,
+                    line,
+                    "an expectation like \"<day> <part> <set> <answer>\"",
+                ));
+            }
+            let day: u8 = parse_at(fields[0], i + 1, 1, "a day")?;
+            let column = fields[0].len() + 2;
+            let part = parse_part(fields[1])
+                .map_err(|_| Error::parse(i + 1, column, fields[1], "a part, a or b"))?;
+            answers.insert((day, part, fields[2].to_string()), fields[3].to_string());
+        }
+
+        return Ok(Expectations { answers });
+    }
+}
+
+impl Expectations {
+    /// Read the expectations, a missing file is the same as an empty one.
+    pub fn from_file(path: &Path) -> Result<Expectations, Error> {
+        if !path.exists() {
+            return Ok(Expectations::default());
+        }
+        return Expectations::from_str(&fs::read_to_string(path)?);
+    }
+
+    /// Replace the answers for an input set of a day in a file, keeping every other line.
+    pub fn record(path: &Path, day: u8, set: &str, a: &str, b: &str) -> Result<(), Error> {
+        let existing = if path.exists() {
+            fs::read_to_string(path)?
+        } else {
+            String::new()
+        };
+
+        let mut output = String::new();
+        for line in existing.lines() {
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            let same = fields.len() == 4
+                && fields[0].parse::<u8>() == Ok(day)
+                && parse_part(fields[1]).is_ok()
+                && fields[2] == set;
+            if !same {
+                output += line;
+                output.push('\n');
+            }
+        }
+        output += &format!("{} a {} {}\n{} b {} {}\n", day, set, a, day, set, b);
+        return Ok(fs::write(path, output)?);
+    }
+
+    /// The known answer to a part of a day on an input set, if any.
+    pub fn expected(&self, day: u8, part: Part, set: &str) -> Option<&String> {
+        return self.answers.get(&(day, part, set.to_string()));
+    }
+
+    pub fn check(&self, day: u8, part: Part, set: &str, answer: &str) -> Verdict {
+        return match self.expected(day, part, set) {
+            Some(a) if a == answer => Verdict::Pass,
+            Some(a) => Verdict::Fail(a.clone()),
+            None => Verdict::Missing,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let expectations = Expectations::from_str(
+            "# Day 21\n21 a input 5\n21 B input mxmxvkd,sqjhc\n\n25 b input Merry Christmas!\n",
+        )
+        .unwrap();
+
+        assert_eq!(expectations.check(21, Part::A, "input", "5"), Verdict::Pass);
+        assert_eq!(
+            expectations.check(21, Part::B, "input", "fvjkl"),
+            Verdict::Fail("mxmxvkd,sqjhc".to_string())
+        );
+        assert_eq!(
+            expectations.check(25, Part::B, "input", "Merry Christmas!"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            expectations.check(21, Part::A, "example", "5"),
+            Verdict::Missing
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let error = Expectations::from_str("1 a input 514579\n1 c input 241861950\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: unexpected \"c\", expected a part, a or b"
+        );
+        assert!(Expectations::from_str("1 a input\n").is_err());
+    }
+
+    #[test]
+    fn record() {
+        let path = std::env::temp_dir().join(format!("answers-{}.txt", std::process::id()));
+        fs::write(&path, "# Seeds\n1 a seed-1 5\n1 b seed-1 6\n2 a seed-1 7\n").unwrap();
+        Expectations::record(&path, 1, "seed-1", "8", "9").unwrap();
+
+        let expectations = Expectations::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(expectations.check(1, Part::A, "seed-1", "8"), Verdict::Pass);
+        assert_eq!(expectations.check(1, Part::B, "seed-1", "9"), Verdict::Pass);
+        assert_eq!(expectations.check(2, Part::A, "seed-1", "7"), Verdict::Pass);
+    }
+}
@@ -0,0 +1,65 @@
+//! Solve the examples in `rust/fixtures` and compare them against the answers stored next to
+//! them.
+//!
+//! Every day has at least one `day_NN.<set>.txt` there, with its answers in `answers.txt` in the
+//! format read by `check`. Only the parts with a known answer are solved, so an example can cover
+//! a single part. Adding a case is a matter of adding a file and its answers.
+
+use crate::days::registry;
+use crate::expect::Expectations;
+use crate::inputs::discover;
+use aoc::Part;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn fixtures_dir() -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures");
+}
+
+#[test]
+fn examples() {
+    let dir = fixtures_dir();
+    let expectations = Expectations::from_file(&dir.join("answers.txt")).unwrap();
+    let registry = registry();
+
+    let mut failures = Vec::new();
+    for solution in registry.days() {
+        let day = solution.day();
+        let inputs = discover(&dir, day);
+        if inputs.is_empty() {
+            failures.push(format!("day {} has no fixtures", day));
+        }
+
+        for input in inputs {
+            let parts: Vec<Part> = [Part::A, Part::B]
+                .iter()
+                .copied()
+                .filter(|p| expectations.expected(day, *p, &input.set).is_some())
+                .collect();
+            if parts.is_empty() {
+                failures.push(format!("day {} {} has no answers", day, input.set));
+                continue;
+            }
+
+            let parsed = match solution.parse(&fs::read_to_string(&input.path).unwrap()) {
+                Ok(p) => p,
+                Err(e) => {
+                    failures.push(format!("day {} {}: {}", day, input.set, e));
+                    continue;
+                }
+            };
+            for part in parts {
+                let expected = expectations.expected(day, part, &input.set).unwrap();
+                match solution.run(parsed.as_ref(), part) {
+                    Ok(a) if &a == expected => {}
+                    Ok(a) => failures.push(format!(
+                        "day {} {} {}: expected {} but got {}",
+                        day, part, input.set, expected, a
+                    )),
+                    Err(e) => failures.push(format!("day {} {} {}: {}", day, part, input.set, e)),
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,102 @@
+use super::{Generated, Rng};
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+const TARGET: i64 = 2020;
+/// At least the planted pair and triple.
+pub const SIZES: RangeInclusive<usize> = 5..=usize::MAX;
+
+/// Entries that are mostly above half the target, with one pair and one triple planted.
+///
+/// Fillers never complete a sum with the planted entries, so duplicate values only show up where
+/// they do not change the answer. The rare planted entries that sum among themselves are redrawn.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    loop {
+        let mut entries = Vec::new();
+
+        let a = rng.range(1, TARGET / 2);
+        entries.extend([a, TARGET - a]);
+        let x = rng.range(1, TARGET / 3);
+        let y = rng.range(x, (TARGET - x) / 2);
+        entries.extend([x, y, TARGET - x - y]);
+
+        // Fillers are above half the target, so they can only sum with the small planted entries.
+        let small: Vec<i64> = entries
+            .iter()
+            .copied()
+            .filter(|e| *e <= TARGET / 2)
+            .collect();
+        let mut taken: HashSet<i64> = entries.iter().copied().collect();
+        for (i, s) in small.iter().enumerate() {
+            taken.insert(TARGET - s);
+            taken.insert(TARGET - 2 * s);
+            for t in &small[i + 1..] {
+                taken.insert(TARGET - s - t);
+            }
+        }
+
+        while entries.len() < size.max(*SIZES.start()) {
+            // Sometimes repeat an earlier filler value.
+            if entries.len() > 5 && rng.chance(0.05) {
+                let i = rng.range(5, entries.len() as i64 - 1) as usize;
+                entries.push(entries[i]);
+            } else {
+                let filler = loop {
+                    let filler = rng.range(TARGET / 2 + 1, TARGET - 1);
+                    if !taken.contains(&filler) {
+                        break filler;
+                    }
+                };
+                entries.push(filler);
+            }
+        }
+        rng.shuffle(&mut entries);
+
+        if let Some((pair, triple)) = solve(&entries) {
+            return Generated {
+                input: entries.iter().map(|e| format!("{}\n", e)).collect(),
+                a: pair.to_string(),
+                b: triple.to_string(),
+            };
+        }
+    }
+}
+
+/// The products of the only pair and the only triple, `None` unless both are unique.
+fn solve(entries: &[i64]) -> Option<(i64, i64)> {
+    let mut counts: HashMap<i64, usize> = HashMap::new();
+    for e in entries {
+        *counts.entry(*e).or_insert(0) += 1;
+    }
+
+    // A value summing with itself only counts if it occurs twice, so any such sum is ambiguous.
+    for (value, count) in &counts {
+        if *count == 1 && (counts.contains_key(&(TARGET - 2 * value)) || 2 * value == TARGET) {
+            return None;
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let mut triples = Vec::new();
+    for i in 0..entries.len() {
+        for j in (i + 1)..entries.len() {
+            let sum = entries[i] + entries[j];
+            if sum == TARGET {
+                pairs.push(entries[i] * entries[j]);
+            }
+            if sum >= TARGET {
+                continue;
+            }
+            for k in (j + 1)..entries.len() {
+                if sum + entries[k] == TARGET {
+                    triples.push(entries[i] * entries[j] * entries[k]);
+                }
+            }
+        }
+    }
+
+    return match (pairs.as_slice(), triples.as_slice()) {
+        ([pair], [triple]) => Some((*pair, *triple)),
+        _ => None,
+    };
+}
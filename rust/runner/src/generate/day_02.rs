@@ -0,0 +1,45 @@
+use super::{Generated, Rng};
+
+/// Policies with positions that always fall inside the password, over a small alphabet so that
+/// letters repeat.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut a, mut b) = (0, 0);
+
+    for _ in 0..size {
+        let low = rng.range(1, 8) as usize;
+        let high = rng.range(low as i64 + 1, low as i64 + 10) as usize;
+        let letter = letter(rng);
+        let length = rng.range(high as i64, high as i64 + 6) as usize;
+        let password: Vec<char> = (0..length).map(|_| letter_or(rng, letter)).collect();
+
+        let count = password.iter().filter(|c| **c == letter).count();
+        if low <= count && count <= high {
+            a += 1;
+        }
+        if (password[low - 1] == letter) != (password[high - 1] == letter) {
+            b += 1;
+        }
+
+        let password: String = password.into_iter().collect();
+        input.push_str(&format!("{}-{} {}: {}\n", low, high, letter, password));
+    }
+
+    return Generated {
+        input,
+        a: a.to_string(),
+        b: b.to_string(),
+    };
+}
+
+fn letter(rng: &mut Rng) -> char {
+    return (b'a' + rng.range(0, 7) as u8) as char;
+}
+
+/// Favour the letter of the policy, so that some passwords pass.
+fn letter_or(rng: &mut Rng, letter: char) -> char {
+    if rng.chance(0.3) {
+        return letter;
+    }
+    return self::letter(rng);
+}
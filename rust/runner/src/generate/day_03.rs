@@ -0,0 +1,38 @@
+use super::{Generated, Rng};
+
+const WIDTH: usize = 31;
+
+/// A map of 31 columns with trees scattered over it.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let map: Vec<Vec<bool>> = (0..size.max(1))
+        .map(|_| (0..WIDTH).map(|_| rng.chance(0.2)).collect())
+        .collect();
+
+    let trees = |right: usize, down: usize| -> i128 {
+        let mut count = 0;
+        let mut step = 0;
+        while step * down < map.len() {
+            if map[step * down][(step * right) % WIDTH] {
+                count += 1;
+            }
+            step += 1;
+        }
+        return count;
+    };
+
+    let b = trees(1, 1) * trees(3, 1) * trees(5, 1) * trees(7, 1) * trees(1, 2);
+    let input = map
+        .iter()
+        .map(|row| {
+            let mut line: String = row.iter().map(|t| if *t { '#' } else { '.' }).collect();
+            line.push('\n');
+            line
+        })
+        .collect();
+
+    return Generated {
+        input,
+        a: trees(3, 1).to_string(),
+        b: b.to_string(),
+    };
+}
@@ -0,0 +1,124 @@
+use super::{Generated, Rng};
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+/// Passports that miss fields now and then, with some values out of range or malformed.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut passports = Vec::new();
+    let (mut a, mut b) = (0, 0);
+
+    for _ in 0..size {
+        let mut fields: Vec<(&str, String)> = Vec::new();
+        for key in REQUIRED.iter() {
+            if rng.chance(0.93) {
+                let value = match rng.chance(0.85) {
+                    true => valid_value(rng, key),
+                    false => invalid_value(rng, key),
+                };
+                fields.push((key, value));
+            }
+        }
+        if rng.chance(0.5) {
+            fields.push(("cid", rng.range(50, 350).to_string()));
+        }
+
+        // The country is optional, all other fields have to be present.
+        if fields.iter().filter(|(k, _)| *k != "cid").count() == REQUIRED.len() {
+            a += 1;
+            if fields.iter().all(|(k, v)| is_valid(k, v)) {
+                b += 1;
+            }
+        }
+
+        rng.shuffle(&mut fields);
+        let mut passport = String::new();
+        for (i, (key, value)) in fields.iter().enumerate() {
+            if i > 0 {
+                passport.push(if rng.chance(0.3) { '\n' } else { ' ' });
+            }
+            passport.push_str(&format!("{}:{}", key, value));
+        }
+        passports.push(passport);
+    }
+
+    return Generated {
+        input: passports.join("\n\n") + "\n",
+        a: a.to_string(),
+        b: b.to_string(),
+    };
+}
+
+fn valid_value(rng: &mut Rng, key: &str) -> String {
+    return match key {
+        "byr" => rng.range(1920, 2002).to_string(),
+        "iyr" => rng.range(2010, 2020).to_string(),
+        "eyr" => rng.range(2020, 2030).to_string(),
+        "hgt" if rng.chance(0.5) => format!("{}cm", rng.range(150, 193)),
+        "hgt" => format!("{}in", rng.range(59, 76)),
+        "hcl" => format!("#{:06x}", rng.range(0, 0xffffff)),
+        "ecl" => rng.choose(&EYE_COLORS).to_string(),
+        _ => format!("{:09}", rng.range(0, 999_999_999)),
+    };
+}
+
+fn invalid_value(rng: &mut Rng, key: &str) -> String {
+    let options: Vec<String> = match key {
+        "byr" => vec![
+            rng.range(1850, 1919).to_string(),
+            rng.range(2003, 2030).to_string(),
+        ],
+        "iyr" => vec![
+            rng.range(1990, 2009).to_string(),
+            rng.range(2021, 2030).to_string(),
+        ],
+        "eyr" => vec![
+            rng.range(2000, 2019).to_string(),
+            rng.range(2031, 2040).to_string(),
+        ],
+        "hgt" => vec![
+            format!("{}cm", rng.range(100, 149)),
+            format!("{}in", rng.range(77, 190)),
+            rng.range(59, 193).to_string(),
+        ],
+        "hcl" => vec![
+            format!("{:06x}", rng.range(0, 0xffffff)),
+            format!("#{:07x}", rng.range(0x1000000, 0xfffffff)),
+            format!("#{:05x}z", rng.range(0, 0xfffff)),
+        ],
+        "ecl" => vec!["xry".to_string(), "zzz".to_string(), "#a97842".to_string()],
+        _ => vec![
+            format!("{:08}", rng.range(0, 99_999_999)),
+            format!("{:010}", rng.range(0, 9_999_999_999)),
+        ],
+    };
+    return rng.choose(&options).clone();
+}
+
+fn is_valid(key: &str, value: &str) -> bool {
+    let year = |low: u32, high: u32| {
+        value.len() == 4 && value.parse::<u32>().is_ok_and(|y| low <= y && y <= high)
+    };
+    return match key {
+        "byr" => year(1920, 2002),
+        "iyr" => year(2010, 2020),
+        "eyr" => year(2020, 2030),
+        "hgt" => match (value.strip_suffix("cm"), value.strip_suffix("in")) {
+            (Some(cm), _) => cm.parse::<u32>().is_ok_and(|h| (150..=193).contains(&h)),
+            (_, Some(inch)) => inch.parse::<u32>().is_ok_and(|h| (59..=76).contains(&h)),
+            _ => false,
+        },
+        "hcl" => match value.strip_prefix('#') {
+            Some(hex) => {
+                hex.len() == 6
+                    && hex
+                        .chars()
+                        .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())
+            }
+            None => false,
+        },
+        "ecl" => EYE_COLORS.contains(&value),
+        "pid" => value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()),
+        _ => true,
+    };
+}
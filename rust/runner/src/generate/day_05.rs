@@ -0,0 +1,40 @@
+use super::{Generated, Rng};
+use std::ops::RangeInclusive;
+
+/// Every seat ID fits in 10 bits, and the empty seat needs a neighbour on both sides.
+pub const SIZES: RangeInclusive<usize> = 3..=1000;
+
+/// A full flight of consecutive seat IDs with a single empty seat in between.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.clamp(*SIZES.start(), *SIZES.end()) as i64;
+    let first = rng.range(1, 1023 - size);
+    let last = first + size;
+    let empty = rng.range(first + 1, last - 1);
+
+    let mut seats: Vec<i64> = (first..=last).filter(|s| *s != empty).collect();
+    rng.shuffle(&mut seats);
+
+    let input = seats
+        .iter()
+        .map(|seat| {
+            // The row takes the upper seven bits and the column the lower three.
+            let mut pass: String = (0..10)
+                .rev()
+                .map(|bit| match (bit >= 3, seat >> bit & 1 == 1) {
+                    (true, false) => 'F',
+                    (true, true) => 'B',
+                    (false, false) => 'L',
+                    (false, true) => 'R',
+                })
+                .collect();
+            pass.push('\n');
+            pass
+        })
+        .collect();
+
+    return Generated {
+        input,
+        a: last.to_string(),
+        b: empty.to_string(),
+    };
+}
@@ -0,0 +1,46 @@
+use super::{Generated, Rng};
+use std::collections::BTreeSet;
+
+/// Groups of one to five people, who mostly answer the same questions as the rest of the group.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut groups = Vec::new();
+    let (mut a, mut b) = (0, 0);
+
+    for _ in 0..size {
+        let common: Vec<char> = ('a'..='z').filter(|_| rng.chance(0.3)).collect();
+        let mut people: Vec<BTreeSet<char>> = Vec::new();
+        for _ in 0..rng.range(1, 5) {
+            let mut answers: BTreeSet<char> =
+                common.iter().filter(|_| rng.chance(0.8)).copied().collect();
+            answers.extend(('a'..='z').filter(|_| rng.chance(0.05)));
+            // Every person answers at least one question, an empty line would end the group.
+            if answers.is_empty() {
+                answers.insert((b'a' + rng.range(0, 25) as u8) as char);
+            }
+            people.push(answers);
+        }
+
+        let union: BTreeSet<char> = people.iter().flatten().copied().collect();
+        a += union.len();
+        b += union
+            .iter()
+            .filter(|c| people.iter().all(|p| p.contains(c)))
+            .count();
+
+        let lines: Vec<String> = people
+            .iter()
+            .map(|p| {
+                let mut answers: Vec<char> = p.iter().copied().collect();
+                rng.shuffle(&mut answers);
+                answers.into_iter().collect()
+            })
+            .collect();
+        groups.push(lines.join("\n"));
+    }
+
+    return Generated {
+        input: groups.join("\n\n") + "\n",
+        a: a.to_string(),
+        b: b.to_string(),
+    };
+}
@@ -0,0 +1,134 @@
+use super::{Generated, Rng};
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+const TARGET: &str = "shiny gold";
+const ADJECTIVES: [&str; 33] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "bold", "dusky",
+    "glossy", "hazy", "matte", "misty", "neon", "rusty", "silky", "smoky", "soft", "stark",
+    "frosted", "gentle", "quiet",
+];
+const COLORS: [&str; 33] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+];
+const LEVELS: usize = 7;
+/// Shiny gold and another bag, up to every color there is a name for.
+pub const SIZES: RangeInclusive<usize> = 2..=ADJECTIVES.len() * COLORS.len();
+
+/// Bags on a few levels, where bags only contain bags of deeper levels so that there are no
+/// cycles. Many colors are not connected to shiny gold at all.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut names: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|a| COLORS.iter().map(move |c| format!("{} {}", a, c)))
+        .filter(|n| n != TARGET)
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(*SIZES.start(), *SIZES.end()) - 1);
+    names.push(TARGET.to_string());
+
+    let mut levels: Vec<usize> = names.iter().map(|_| rng.index(LEVELS)).collect();
+    *levels.last_mut().unwrap() = LEVELS / 2;
+
+    let mut contents: HashMap<&str, Vec<(usize, &str)>> = HashMap::new();
+    for (i, name) in names.iter().enumerate() {
+        let deeper: Vec<&str> = (0..names.len())
+            .filter(|j| levels[*j] > levels[i])
+            .map(|j| names[j].as_str())
+            .collect();
+        let mut inside = Vec::new();
+        if !deeper.is_empty() {
+            for _ in 0..rng.range(0, 3) {
+                let bag = *rng.choose(&deeper);
+                if inside.iter().all(|(_, b)| *b != bag) {
+                    inside.push((rng.range(1, 4) as usize, bag));
+                }
+            }
+        }
+        contents.insert(name, inside);
+    }
+
+    let mut rules: Vec<String> = names
+        .iter()
+        .map(|name| {
+            let inside = &contents[name.as_str()];
+            if inside.is_empty() {
+                return format!("{} bags contain no other bags.\n", name);
+            }
+            let bags: Vec<String> = inside
+                .iter()
+                .map(|(n, bag)| format!("{} {} bag{}", n, bag, if *n == 1 { "" } else { "s" }))
+                .collect();
+            format!("{} bags contain {}.\n", name, bags.join(", "))
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    let a = names
+        .iter()
+        .filter(|n| n.as_str() != TARGET && contains(&contents, n, &mut HashSet::new()))
+        .count();
+    return Generated {
+        input: rules.concat(),
+        a: a.to_string(),
+        b: count(&contents, TARGET).to_string(),
+    };
+}
+
+/// Whether a bag eventually holds a shiny gold one.
+fn contains<'a>(
+    contents: &HashMap<&str, Vec<(usize, &'a str)>>,
+    bag: &str,
+    seen: &mut HashSet<&'a str>,
+) -> bool {
+    for (_, inside) in &contents[bag] {
+        if *inside == TARGET {
+            return true;
+        }
+        if seen.insert(inside) && contains(contents, inside, seen) {
+            return true;
+        }
+    }
+    return false;
+}
+
+/// The number of bags inside a bag.
+fn count(contents: &HashMap<&str, Vec<(usize, &str)>>, bag: &str) -> usize {
+    return contents[bag]
+        .iter()
+        .map(|(n, inside)| n * (1 + count(contents, inside)))
+        .sum();
+}
@@ -0,0 +1,106 @@
+use super::{Generated, Rng};
+use std::ops::RangeInclusive;
+
+/// Enough instructions for a loop with a jump to fix.
+pub const SIZES: RangeInclusive<usize> = 4..=usize::MAX;
+use std::collections::HashSet;
+
+#[derive(Clone, Copy)]
+enum Op {
+    Acc(i64),
+    Jmp(i64),
+    Nop(i64),
+}
+
+/// A program that only jumps forward, with one instruction on its path turned into a jump back.
+///
+/// Programs where another flipped instruction also terminates are rejected, and every jump stays
+/// within the program, also after flipping.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(*SIZES.start()) as i64;
+    loop {
+        let mut program: Vec<Op> = (0..size)
+            .map(|i| {
+                let ahead = rng.range(1, (size - i).min(12));
+                // A nop keeps a value that would jump back when flipped, if there is room.
+                let back = -rng.range(0, i.min(12));
+                match rng.range(0, 9) {
+                    0..=4 => Op::Acc(rng.range(-50, 50)),
+                    5..=7 => Op::Jmp(ahead),
+                    _ => Op::Nop(back),
+                }
+            })
+            .collect();
+
+        // Break the program with a jump back into its own path.
+        let path = trace(&program).0;
+        let candidates: Vec<i64> = path.iter().copied().filter(|i| *i > 0).collect();
+        if candidates.is_empty() {
+            continue;
+        }
+        let broken = *rng.choose(&candidates);
+        let earlier: Vec<i64> = path.iter().copied().filter(|i| *i <= broken).collect();
+        program[broken as usize] = Op::Jmp(rng.choose(&earlier) - broken);
+
+        let (_, a, looped) = trace(&program);
+        if !looped {
+            continue;
+        }
+
+        let mut fixes = Vec::new();
+        for i in 0..program.len() {
+            let mut fixed = program.clone();
+            fixed[i] = match program[i] {
+                Op::Acc(_) => continue,
+                Op::Jmp(v) => Op::Nop(v),
+                Op::Nop(v) => Op::Jmp(v),
+            };
+            let (_, acc, looped) = trace(&fixed);
+            if !looped {
+                fixes.push(acc);
+            }
+        }
+        if fixes.len() != 1 {
+            continue;
+        }
+
+        let input = program
+            .iter()
+            .map(|op| match op {
+                Op::Acc(v) => format!("acc {:+}\n", v),
+                Op::Jmp(v) => format!("jmp {:+}\n", v),
+                Op::Nop(v) => format!("nop {:+}\n", v),
+            })
+            .collect();
+        return Generated {
+            input,
+            a: a.to_string(),
+            b: fixes[0].to_string(),
+        };
+    }
+}
+
+/// Run until an instruction repeats or the program ends, returns the instructions that ran, the
+/// accumulator and whether it looped.
+fn trace(program: &[Op]) -> (Vec<i64>, i64, bool) {
+    let mut path = Vec::new();
+    let mut seen = HashSet::new();
+    let mut acc = 0;
+    let mut i: i64 = 0;
+
+    while (i as usize) < program.len() {
+        if !seen.insert(i) {
+            return (path, acc, true);
+        }
+        path.push(i);
+        match program[i as usize] {
+            Op::Acc(v) => {
+                acc += v;
+                i += 1;
+            }
+            Op::Jmp(v) => i += v,
+            Op::Nop(_) => i += 1,
+        }
+    }
+    return (path, acc, false);
+}
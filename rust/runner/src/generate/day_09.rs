@@ -0,0 +1,73 @@
+use super::{Generated, Rng};
+use std::ops::RangeInclusive;
+
+const PREAMBLE: usize = 25;
+/// The preamble and a few numbers after it.
+pub const SIZES: RangeInclusive<usize> = PREAMBLE + 5..=usize::MAX;
+
+/// Numbers that are each the sum of two small, different numbers among the 25 before them, with
+/// one invalid number that is the sum of a run of numbers near the start.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(*SIZES.start());
+    loop {
+        let mut preamble: Vec<i64> = (1..=PREAMBLE as i64 * 2).collect();
+        rng.shuffle(&mut preamble);
+        preamble.truncate(PREAMBLE);
+
+        let invalid_at = rng.range((size / 2) as i64, size as i64 - 1) as usize;
+        let mut numbers = preamble;
+        while numbers.len() < invalid_at {
+            numbers.push(next_valid(rng, &numbers[numbers.len() - PREAMBLE..]));
+        }
+
+        // A short run of early numbers, small enough not to be the sum of any two in the window.
+        let start = rng.index(10);
+        let length = rng.range(2, 5) as usize;
+        let target: i64 = numbers[start..start + length].iter().sum();
+        let window = &numbers[numbers.len() - PREAMBLE..];
+        if window
+            .iter()
+            .any(|a| window.iter().any(|b| a + b == target))
+            || numbers.contains(&target)
+        {
+            continue;
+        }
+        numbers.push(target);
+        while numbers.len() < size {
+            numbers.push(next_valid(rng, &numbers[numbers.len() - PREAMBLE..]));
+        }
+
+        let input = numbers.iter().map(|n| format!("{}\n", n)).collect();
+        return Generated {
+            input,
+            a: target.to_string(),
+            b: weakness(&numbers, target).to_string(),
+        };
+    }
+}
+
+/// The sum of two different numbers, picked among the smallest ones to keep the numbers from
+/// growing too fast.
+fn next_valid(rng: &mut Rng, window: &[i64]) -> i64 {
+    let mut sorted = window.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let last = sorted.len().min(4) - 1;
+    let a = rng.index(last);
+    let b = rng.range(a as i64 + 1, last as i64) as usize;
+    return sorted[a] + sorted[b];
+}
+
+/// The smallest and largest number of the first run of at least two numbers that sum to the
+/// target, added together.
+fn weakness(numbers: &[i64], target: i64) -> i64 {
+    for start in 0..numbers.len() {
+        for end in start + 2..=numbers.len() {
+            let run = &numbers[start..end];
+            if run.iter().sum::<i64>() == target {
+                return run.iter().min().unwrap() + run.iter().max().unwrap();
+            }
+        }
+    }
+    unreachable!("The run that made the target is always there");
+}
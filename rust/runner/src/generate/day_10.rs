@@ -0,0 +1,57 @@
+use super::{Generated, Rng};
+
+/// Stop adding adapters before the number of arrangements gets out of hand.
+const MAX_ARRANGEMENTS: u128 = 1_000_000_000_000_000;
+
+/// Adapters in runs that differ by 1 jolt, of at most four, separated by gaps of 3 jolts.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut adapters = Vec::new();
+    let mut joltage = 0;
+    while adapters.len() < size.max(1) {
+        if !adapters.is_empty() {
+            joltage += 3;
+            adapters.push(joltage);
+        }
+        for _ in 0..rng.range(1, 4) {
+            joltage += 1;
+            adapters.push(joltage);
+        }
+        if arrangements(&adapters) > MAX_ARRANGEMENTS {
+            break;
+        }
+    }
+
+    let mut chain = adapters.clone();
+    chain.insert(0, 0);
+    chain.push(joltage + 3);
+    let ones = chain.windows(2).filter(|w| w[1] - w[0] == 1).count();
+    let threes = chain.windows(2).filter(|w| w[1] - w[0] == 3).count();
+    let b = arrangements(&adapters);
+
+    rng.shuffle(&mut adapters);
+    let input = adapters.iter().map(|a| format!("{}\n", a)).collect();
+    return Generated {
+        input,
+        a: (ones * threes).to_string(),
+        b: b.to_string(),
+    };
+}
+
+/// The ways to get from the outlet to the device, counted per adapter in increasing order.
+fn arrangements(adapters: &[i64]) -> u128 {
+    let mut chain = adapters.to_vec();
+    chain.sort_unstable();
+    chain.insert(0, 0);
+
+    let mut ways = vec![0u128; chain.len()];
+    ways[0] = 1;
+    for i in 1..chain.len() {
+        for j in (0..i).rev() {
+            if chain[i] - chain[j] > 3 {
+                break;
+            }
+            ways[i] += ways[j];
+        }
+    }
+    return ways[chain.len() - 1];
+}
@@ -0,0 +1,83 @@
+use super::{Generated, Rng};
+
+/// Random layouts can make people get up and sit down forever, give up on those after this many
+/// rounds.
+const MAX_ROUNDS: usize = 1000;
+
+/// A square waiting area where most positions are seats, in which people do settle down.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    loop {
+        let layout: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.chance(0.8) { 'L' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        let (a, b) = match (settle(&layout, false, 4), settle(&layout, true, 5)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => continue,
+        };
+        let input = layout
+            .iter()
+            .map(|row| format!("{}\n", row.iter().collect::<String>()))
+            .collect();
+        return Generated {
+            input,
+            a: a.to_string(),
+            b: b.to_string(),
+        };
+    }
+}
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Let people sit down and get up until nothing changes, returns the occupied seats.
+fn settle(layout: &[Vec<char>], far: bool, tolerance: usize) -> Option<usize> {
+    let mut seats = layout.to_vec();
+    for _ in 0..MAX_ROUNDS {
+        let mut next = seats.clone();
+        for (y, row) in seats.iter().enumerate() {
+            for (x, seat) in row.iter().enumerate() {
+                let occupied = DIRECTIONS
+                    .iter()
+                    .filter(|(dy, dx)| look(&seats, y, x, *dy, *dx, far) == Some('#'))
+                    .count();
+                next[y][x] = match seat {
+                    'L' if occupied == 0 => '#',
+                    '#' if occupied >= tolerance => 'L',
+                    s => *s,
+                };
+            }
+        }
+        if next == seats {
+            return Some(seats.iter().flatten().filter(|s| **s == '#').count());
+        }
+        seats = next;
+    }
+    return None;
+}
+
+/// The first seat in a direction, only the adjacent position unless looking far.
+fn look(seats: &[Vec<char>], y: usize, x: usize, dy: isize, dx: isize, far: bool) -> Option<char> {
+    let (mut y, mut x) = (y as isize, x as isize);
+    loop {
+        y += dy;
+        x += dx;
+        let seat = *seats.get(y as usize)?.get(x as usize)?;
+        if seat != '.' || !far {
+            return Some(seat);
+        }
+    }
+}
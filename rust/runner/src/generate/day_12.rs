@@ -0,0 +1,61 @@
+use super::{Generated, Rng};
+
+/// Random navigation instructions, turns are always whole quarters.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let actions: Vec<(char, i64)> = (0..size.max(1))
+        .map(|_| match rng.range(0, 9) {
+            0..=1 => (*rng.choose(&['L', 'R']), rng.range(1, 3) * 90),
+            2..=4 => ('F', rng.range(1, 100)),
+            _ => (*rng.choose(&['N', 'E', 'S', 'W']), rng.range(1, 5)),
+        })
+        .collect();
+
+    let input = actions
+        .iter()
+        .map(|(action, value)| format!("{}{}\n", action, value))
+        .collect();
+    return Generated {
+        input,
+        a: navigate(&actions, (1, 0), false).to_string(),
+        b: navigate(&actions, (10, 1), true).to_string(),
+    };
+}
+
+/// Follow the actions, moving the waypoint instead of the ship with compass actions if asked.
+/// Returns the Manhattan distance from the start.
+fn navigate(actions: &[(char, i64)], waypoint: (i64, i64), move_waypoint: bool) -> i64 {
+    let (mut east, mut north) = (0, 0);
+    let (mut dx, mut dy) = waypoint;
+    for (action, value) in actions {
+        let step = match action {
+            'N' => (0, *value),
+            'S' => (0, -value),
+            'E' => (*value, 0),
+            'W' => (-value, 0),
+            'L' | 'R' => {
+                let quarters = if *action == 'L' {
+                    value / 90
+                } else {
+                    4 - value / 90
+                };
+                for _ in 0..quarters {
+                    (dx, dy) = (-dy, dx);
+                }
+                continue;
+            }
+            _ => {
+                east += dx * value;
+                north += dy * value;
+                continue;
+            }
+        };
+        if move_waypoint {
+            dx += step.0;
+            dy += step.1;
+        } else {
+            east += step.0;
+            north += step.1;
+        }
+    }
+    return east.abs() + north.abs();
+}
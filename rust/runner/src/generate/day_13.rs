@@ -0,0 +1,72 @@
+use super::{Generated, Rng};
+use aoc::math::lcm;
+
+/// Keep the timestamp of part B well within an `isize`.
+const MAX_PERIOD: i128 = 100_000_000_000_000_000;
+
+/// Buses placed in the schedule at the offsets where they line up at some timestamp, so that
+/// part B always has a solution, even with bus IDs that share factors.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    loop {
+        let timestamp = rng.range(0, 1_000_000_000_000);
+        let length = size.max(1) * 5;
+        let mut schedule: Vec<Option<i64>> = vec![None; length];
+        let mut period = 1;
+
+        for _ in 0..size.max(1) {
+            let id = rng.range(5, 97);
+            let offsets: Vec<usize> = (0..length)
+                .filter(|i| (timestamp + *i as i64) % id == 0 && schedule[*i].is_none())
+                .collect();
+            let next_period = lcm(period, i128::from(id)).unwrap_or(i128::MAX);
+            if offsets.is_empty() || schedule.contains(&Some(id)) || next_period > MAX_PERIOD {
+                continue;
+            }
+            schedule[*rng.choose(&offsets)] = Some(id);
+            period = next_period;
+        }
+
+        let buses: Vec<i64> = schedule.iter().flatten().copied().collect();
+        let earliest = rng.range(1000, 1_000_000);
+        let mut departures: Vec<(i64, i64)> = buses
+            .iter()
+            .map(|id| ((earliest + id - 1) / id * id, *id))
+            .collect();
+        departures.sort_unstable();
+        // Avoid buses leaving right at the earliest time, or two buses leaving together.
+        if departures.is_empty()
+            || departures[0].0 == earliest
+            || departures.get(1).is_some_and(|d| d.0 == departures[0].0)
+        {
+            continue;
+        }
+        let (departure, id) = departures[0];
+
+        let ids: Vec<String> = schedule
+            .iter()
+            .map(|bus| bus.map_or("x".to_string(), |id| id.to_string()))
+            .collect();
+        return Generated {
+            input: format!("{}\n{}\n", earliest, ids.join(",")),
+            a: ((departure - earliest) * id).to_string(),
+            b: line_up(&schedule).to_string(),
+        };
+    }
+}
+
+/// The first timestamp where every bus leaves at its offset, adding the buses one by one and
+/// stepping by the period of the buses so far.
+fn line_up(schedule: &[Option<i64>]) -> i128 {
+    let mut timestamp = 0;
+    let mut step = 1;
+    for (offset, bus) in schedule.iter().enumerate() {
+        if let Some(id) = bus {
+            let id = i128::from(*id);
+            while (timestamp + offset as i128) % id != 0 {
+                timestamp += step;
+            }
+            step = lcm(step, id).unwrap();
+        }
+    }
+    return timestamp;
+}
@@ -0,0 +1,80 @@
+use super::{Generated, Rng};
+use std::collections::HashMap;
+
+/// Floating bits are expanded in part B, so keep their number low like in the puzzle.
+const MAX_FLOATING: usize = 8;
+
+enum Instruction {
+    Mask(String),
+    Mem(u64, u64),
+}
+
+/// Masks, each followed by a few writes to memory.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut program = Vec::new();
+    for _ in 0..size.max(1) {
+        let mut mask: Vec<char> = (0..36)
+            .map(|_| if rng.chance(0.5) { '0' } else { '1' })
+            .collect();
+        for _ in 0..rng.range(0, MAX_FLOATING as i64) {
+            mask[rng.index(36)] = 'X';
+        }
+        program.push(Instruction::Mask(mask.into_iter().collect()));
+        for _ in 0..rng.range(1, 5) {
+            program.push(Instruction::Mem(
+                rng.range(0, 65535) as u64,
+                rng.range(0, (1 << 36) - 1) as u64,
+            ));
+        }
+    }
+
+    let input = program
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mask(mask) => format!("mask = {}\n", mask),
+            Instruction::Mem(address, value) => format!("mem[{}] = {}\n", address, value),
+        })
+        .collect();
+    return Generated {
+        input,
+        a: run(&program, false).to_string(),
+        b: run(&program, true).to_string(),
+    };
+}
+
+/// The sum of the memory after running the program, with the mask decoding the address instead
+/// of the value if asked.
+fn run(program: &[Instruction], decode_address: bool) -> u64 {
+    let mut memory = HashMap::new();
+    let (mut ones, mut zeros, mut floating) = (0u64, 0u64, Vec::new());
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(mask) => {
+                ones = 0;
+                zeros = 0;
+                floating.clear();
+                for (i, bit) in mask.chars().rev().enumerate() {
+                    match bit {
+                        '1' => ones |= 1 << i,
+                        '0' => zeros |= 1 << i,
+                        _ => floating.push(i),
+                    }
+                }
+            }
+            Instruction::Mem(address, value) if !decode_address => {
+                memory.insert(*address, (value | ones) & !zeros);
+            }
+            Instruction::Mem(address, value) => {
+                for combination in 0..1u64 << floating.len() {
+                    let mut decoded = address | ones;
+                    for (j, bit) in floating.iter().enumerate() {
+                        decoded &= !(1 << bit);
+                        decoded |= (combination >> j & 1) << bit;
+                    }
+                    memory.insert(decoded, *value);
+                }
+            }
+        }
+    }
+    return memory.values().sum();
+}
@@ -0,0 +1,35 @@
+use super::{Generated, Rng};
+use std::ops::RangeInclusive;
+
+/// The starting numbers are different and at most 20.
+pub const SIZES: RangeInclusive<usize> = 1..=21;
+
+/// Different small starting numbers.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut numbers: Vec<usize> = (0..=20).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(size.clamp(*SIZES.start(), *SIZES.end()));
+
+    let starting: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    return Generated {
+        input: format!("{}\n", starting.join(",")),
+        a: play(&numbers, 2020).to_string(),
+        b: play(&numbers, 30_000_000).to_string(),
+    };
+}
+
+/// The number spoken at a turn, remembering the last turn of every number in a flat table.
+fn play(starting: &[usize], turns: usize) -> usize {
+    let mut last_turn = vec![0u32; turns.max(21)];
+    let mut spoken = starting[0];
+    for turn in 1..turns {
+        let next = match starting.get(turn) {
+            Some(n) => *n,
+            None if last_turn[spoken] == 0 => 0,
+            None => turn - last_turn[spoken] as usize,
+        };
+        last_turn[spoken] = turn as u32;
+        spoken = next;
+    }
+    return spoken;
+}
@@ -0,0 +1,101 @@
+use super::{Generated, Rng};
+use std::ops::RangeInclusive;
+
+const FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+/// One column per field at most.
+pub const SIZES: RangeInclusive<usize> = 1..=FIELDS.len();
+
+/// Fields with nested ranges, where the n-th field allows values up to 10n, so that the fields can
+/// be told apart one at a time. Invalid values are above every range.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.clamp(*SIZES.start(), *SIZES.end());
+    // There is always at least one departure field.
+    let mut names: Vec<&str> = FIELDS[1..].to_vec();
+    rng.shuffle(&mut names);
+    names.truncate(count - 1);
+    names.push(FIELDS[0]);
+    rng.shuffle(&mut names);
+
+    // The name of the field with ranges up to 10n is names[n - 1], column[n - 1] is its column.
+    let mut columns: Vec<usize> = (0..count).collect();
+    rng.shuffle(&mut columns);
+    let limit = |column: usize| (columns.iter().position(|c| *c == column).unwrap() + 1) * 10;
+
+    let ticket = |rng: &mut Rng| -> Vec<usize> {
+        return (0..count)
+            .map(|column| match rng.range(0, 9) {
+                0 => rng.range(900, 950) as usize,
+                _ => rng.range(1, limit(column) as i64) as usize,
+            })
+            .collect();
+    };
+
+    let mine = ticket(rng);
+    let mut nearby: Vec<Vec<usize>> = (0..count * 5 + 10).map(|_| ticket(rng)).collect();
+    let valid: Vec<usize> = (0..nearby.len())
+        .filter(|i| *i == 0 || !rng.chance(0.25))
+        .collect();
+
+    // Every column needs a valid value that rules out the fields with lower limits.
+    for column in 0..count {
+        let value = rng.range(limit(column) as i64 - 9, limit(column) as i64) as usize;
+        let ticket = &mut nearby[*rng.choose(&valid)];
+        ticket[column] = value;
+    }
+
+    let mut error_rate = 0;
+    for (i, ticket) in nearby.iter_mut().enumerate() {
+        if !valid.contains(&i) {
+            let value = rng.range(951, 999) as usize;
+            ticket[rng.index(count)] = value;
+            error_rate += value;
+        }
+    }
+
+    let departures: usize = (0..count)
+        .filter(|column| names[limit(*column) / 10 - 1].starts_with("departure"))
+        .map(|column| mine[column])
+        .product();
+
+    let mut input = String::new();
+    for (n, name) in names.iter().enumerate() {
+        input += &format!("{}: 1-{} or 900-950\n", name, (n + 1) * 10);
+    }
+    input += "\nyour ticket:\n";
+    input += &join(&mine);
+    input += "\nnearby tickets:\n";
+    for ticket in &nearby {
+        input += &join(ticket);
+    }
+    return Generated {
+        input,
+        a: error_rate.to_string(),
+        b: departures.to_string(),
+    };
+}
+
+fn join(values: &[usize]) -> String {
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    return format!("{}\n", values.join(","));
+}
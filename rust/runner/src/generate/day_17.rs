@@ -0,0 +1,70 @@
+use super::{Generated, Rng};
+use std::collections::{HashMap, HashSet};
+
+/// A square starting slice with about a third of the cubes active.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    let slice: Vec<Vec<bool>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.chance(0.35)).collect())
+        .collect();
+
+    let input = slice
+        .iter()
+        .map(|row| {
+            let row: String = row.iter().map(|c| if *c { '#' } else { '.' }).collect();
+            format!("{}\n", row)
+        })
+        .collect();
+    return Generated {
+        input,
+        a: boot(&slice, 3).to_string(),
+        b: boot(&slice, 4).to_string(),
+    };
+}
+
+/// Run the six boot cycles in three or four dimensions, returns the active cubes.
+fn boot(slice: &[Vec<bool>], dimensions: usize) -> usize {
+    let mut active: HashSet<[i64; 4]> = HashSet::new();
+    for (x, row) in slice.iter().enumerate() {
+        for (y, cube) in row.iter().enumerate() {
+            if *cube {
+                active.insert([x as i64, y as i64, 0, 0]);
+            }
+        }
+    }
+
+    let range = |d: usize| if d < dimensions { -1..=1 } else { 0..=0 };
+    let mut offsets = Vec::new();
+    for dx in range(0) {
+        for dy in range(1) {
+            for dz in range(2) {
+                for dw in range(3) {
+                    if [dx, dy, dz, dw] != [0; 4] {
+                        offsets.push([dx, dy, dz, dw]);
+                    }
+                }
+            }
+        }
+    }
+
+    for _ in 0..6 {
+        let mut counts: HashMap<[i64; 4], usize> = HashMap::new();
+        for cube in &active {
+            for offset in &offsets {
+                let neighbour = [
+                    cube[0] + offset[0],
+                    cube[1] + offset[1],
+                    cube[2] + offset[2],
+                    cube[3] + offset[3],
+                ];
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+        active = counts
+            .into_iter()
+            .filter(|(cube, count)| *count == 3 || (*count == 2 && active.contains(cube)))
+            .map(|(cube, _)| cube)
+            .collect();
+    }
+    return active.len();
+}
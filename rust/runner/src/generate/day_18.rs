@@ -0,0 +1,77 @@
+use super::{Generated, Rng};
+
+/// Expressions of single digits, with about this many of them.
+const MAX_DIGITS: usize = 12;
+/// Expressions with larger results are thrown away, so that the sum fits easily.
+const MAX_RESULT: u128 = 1_000_000_000_000_000;
+
+/// Random expressions with nested parentheses.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let (mut a, mut b, mut count) = (0, 0, 0);
+    while count < size.max(1) {
+        let text = expression(rng, 0, &mut 0);
+        let tokens: Vec<char> = text.chars().filter(|c| *c != ' ').collect();
+        let results = (
+            evaluate(&tokens, &mut 0, false),
+            evaluate(&tokens, &mut 0, true),
+        );
+        if results.0 <= MAX_RESULT && results.1 <= MAX_RESULT {
+            input += &format!("{}\n", text);
+            a += results.0;
+            b += results.1;
+            count += 1;
+        }
+    }
+    return Generated {
+        input,
+        a: a.to_string(),
+        b: b.to_string(),
+    };
+}
+
+fn expression(rng: &mut Rng, depth: usize, digits: &mut usize) -> String {
+    let mut text = operand(rng, depth, digits);
+    for _ in 0..rng.range(1, 4) {
+        let operator = if rng.chance(0.5) { '+' } else { '*' };
+        text += &format!(" {} {}", operator, operand(rng, depth, digits));
+    }
+    return text;
+}
+
+fn operand(rng: &mut Rng, depth: usize, digits: &mut usize) -> String {
+    if depth < 2 && *digits + 6 < MAX_DIGITS && rng.chance(0.25) {
+        return format!("({})", expression(rng, depth + 1, digits));
+    }
+    *digits += 1;
+    return rng.range(1, 9).to_string();
+}
+
+/// Evaluate up to the closing parenthesis or the end, from the token at `position`. Either every
+/// operator has the same precedence, or addition goes before multiplication.
+fn evaluate(tokens: &[char], position: &mut usize, plus_first: bool) -> u128 {
+    // The factors still to multiply, with the running sum or result as the last one.
+    let mut factors = vec![value(tokens, position, plus_first)];
+    while *position < tokens.len() && tokens[*position] != ')' {
+        let operator = tokens[*position];
+        *position += 1;
+        let operand = value(tokens, position, plus_first);
+        let last = factors.last_mut().unwrap();
+        match operator {
+            '+' => *last += operand,
+            _ if plus_first => factors.push(operand),
+            _ => *last *= operand,
+        }
+    }
+    *position += 1;
+    return factors.iter().product();
+}
+
+fn value(tokens: &[char], position: &mut usize, plus_first: bool) -> u128 {
+    let token = tokens[*position];
+    *position += 1;
+    if token == '(' {
+        return evaluate(tokens, position, plus_first);
+    }
+    return u128::from(token.to_digit(10).unwrap());
+}
@@ -0,0 +1,112 @@
+use super::{Generated, Rng};
+use std::collections::HashSet;
+
+/// The length of the messages matched by rules 42 and 31.
+const CHUNK: usize = 4;
+
+/// Rules 42 and 31 match chunks that start with an "a" and a "b" respectively, so that the
+/// looping rules of part B can be checked chunk by chunk. Messages are runs of those chunks, some
+/// of them damaged.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut rules = vec![
+        "0: 8 11".to_string(),
+        "8: 42".to_string(),
+        "11: 42 31".to_string(),
+        "1: \"a\"".to_string(),
+        "2: \"b\"".to_string(),
+    ];
+
+    // Every other letter of a chunk is an "a", a "b" or either.
+    let mut languages = Vec::new();
+    for (rule, first) in [(42, 1), (31, 2)] {
+        let mut parts = vec![first.to_string()];
+        let mut letters: Vec<Vec<char>> = vec![vec![if first == 1 { 'a' } else { 'b' }]];
+        for _ in 1..CHUNK {
+            let number = 50 + rules.len();
+            let (text, options) = match rng.range(0, 2) {
+                0 => ("1".to_string(), vec!['a']),
+                1 => ("2".to_string(), vec!['b']),
+                _ => ("1 | 2".to_string(), vec!['a', 'b']),
+            };
+            rules.push(format!("{}: {}", number, text));
+            parts.push(number.to_string());
+            letters.push(options);
+        }
+        rules.push(format!("{}: {}", rule, parts.join(" ")));
+        languages.push(expand(&letters));
+    }
+    rng.shuffle(&mut rules);
+    let (forty_two, thirty_one) = (&languages[0], &languages[1]);
+
+    let mut messages = Vec::new();
+    for _ in 0..size.max(1) {
+        let head = rng.range(1, 5);
+        let tail = rng.range(1, 3);
+        let mut message = String::new();
+        for i in 0..head + tail {
+            let language = if i < head { forty_two } else { thirty_one };
+            let chunk: &String = rng.choose(language);
+            message += chunk;
+        }
+
+        // Damage some messages, which may or may not make them invalid.
+        let mut letters: Vec<char> = message.chars().collect();
+        let (at, letter) = (rng.index(letters.len()), *rng.choose(&['a', 'b']));
+        match rng.range(0, 9) {
+            0 => letters[at] = letter,
+            1 => {
+                letters.remove(at);
+            }
+            2 => letters.insert(at, letter),
+            _ => {}
+        }
+        messages.push(letters.into_iter().collect::<String>());
+    }
+
+    let matches = |looping: bool| -> usize {
+        return messages
+            .iter()
+            .filter(|m| matches(m, forty_two, thirty_one, looping))
+            .count();
+    };
+    return Generated {
+        input: format!("{}\n\n{}\n", rules.join("\n"), messages.join("\n")),
+        a: matches(false).to_string(),
+        b: matches(true).to_string(),
+    };
+}
+
+/// All words with one of the letters at every position.
+fn expand(letters: &[Vec<char>]) -> Vec<String> {
+    let mut words = vec![String::new()];
+    for options in letters {
+        words = words
+            .iter()
+            .flat_map(|w| options.iter().map(move |l| format!("{}{}", w, l)))
+            .collect();
+    }
+    return words;
+}
+
+/// Whether a message is some chunks of 42 followed by some chunks of 31, with exactly two and one
+/// of them without the looping rules and more of the first otherwise.
+fn matches(message: &str, forty_two: &[String], thirty_one: &[String], looping: bool) -> bool {
+    if !message.len().is_multiple_of(CHUNK) {
+        return false;
+    }
+    let forty_two: HashSet<&str> = forty_two.iter().map(|s| s.as_str()).collect();
+    let thirty_one: HashSet<&str> = thirty_one.iter().map(|s| s.as_str()).collect();
+
+    let chunks: Vec<&str> = (0..message.len() / CHUNK)
+        .map(|i| &message[i * CHUNK..(i + 1) * CHUNK])
+        .collect();
+    let head = chunks.iter().take_while(|c| forty_two.contains(*c)).count();
+    let tail = chunks.len() - head;
+    if !chunks[head..].iter().all(|c| thirty_one.contains(c)) {
+        return false;
+    }
+    if looping {
+        return tail >= 1 && head > tail;
+    }
+    return head == 2 && tail == 1;
+}
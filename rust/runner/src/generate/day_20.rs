@@ -0,0 +1,192 @@
+use super::{Generated, Rng};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// Tiles are 10 by 10, with 8 by 8 of image inside their borders.
+const TILE: usize = 10;
+const INSIDE: usize = TILE - 2;
+const MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// Tiles per side, see [`generate`] for the upper bound.
+pub const SIZES: RangeInclusive<usize> = 2..=12;
+
+type Image = Vec<Vec<bool>>;
+
+/// An image with some sea monsters, cut into tiles with random borders.
+///
+/// There are only so many borders of 10 pixels, so there are at most 12 tiles per side like in
+/// the puzzle. Borders are never empty, never symmetric and only ever shared by the two tiles
+/// they separate, so that the tiles fit together in just one way. Every tile is then rotated or
+/// flipped.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.clamp(*SIZES.start(), *SIZES.end());
+    let (image, roughness) = image(rng, side * INSIDE);
+
+    // Corner pixels are shared by the borders that meet there.
+    let corners: Vec<Vec<bool>> = (0..=side)
+        .map(|_| (0..=side).map(|_| rng.chance(0.5)).collect())
+        .collect();
+    let mut seen = HashSet::new();
+    let mut border = |rng: &mut Rng, start: bool, end: bool| -> Vec<bool> {
+        loop {
+            let mut pixels = vec![start];
+            pixels.extend((0..INSIDE).map(|_| rng.chance(0.5)));
+            pixels.push(end);
+            let reversed: Vec<bool> = pixels.iter().rev().copied().collect();
+            if pixels.contains(&true) && pixels != reversed && !seen.contains(&pixels) {
+                seen.insert(reversed);
+                seen.insert(pixels.clone());
+                return pixels;
+            }
+        }
+    };
+    // Horizontal borders are indexed by the row of tiles below them, vertical ones by the column
+    // to their right.
+    let horizontal: Vec<Vec<Vec<bool>>> = (0..=side)
+        .map(|r| {
+            (0..side)
+                .map(|c| border(rng, corners[r][c], corners[r][c + 1]))
+                .collect()
+        })
+        .collect();
+    let vertical: Vec<Vec<Vec<bool>>> = (0..side)
+        .map(|r| {
+            (0..=side)
+                .map(|c| border(rng, corners[r][c], corners[r + 1][c]))
+                .collect()
+        })
+        .collect();
+
+    let mut ids: Vec<usize> = (1000..10000).collect();
+    rng.shuffle(&mut ids);
+
+    let mut tiles = Vec::new();
+    for r in 0..side {
+        for c in 0..side {
+            let mut tile = vec![vec![false; TILE]; TILE];
+            for i in 0..TILE {
+                tile[0][i] = horizontal[r][c][i];
+                tile[TILE - 1][i] = horizontal[r + 1][c][i];
+                tile[i][0] = vertical[r][c][i];
+                tile[i][TILE - 1] = vertical[r][c + 1][i];
+            }
+            for y in 0..INSIDE {
+                for x in 0..INSIDE {
+                    tile[y + 1][x + 1] = image[r * INSIDE + y][c * INSIDE + x];
+                }
+            }
+            tiles.push((ids[r * side + c], orient(&tile, rng.index(8))));
+        }
+    }
+
+    let last = side - 1;
+    let corners: usize = [0, last, last * side, last * side + last]
+        .iter()
+        .map(|i| tiles[*i].0)
+        .product();
+
+    rng.shuffle(&mut tiles);
+    let input: Vec<String> = tiles
+        .iter()
+        .map(|(id, tile)| {
+            let rows: Vec<String> = tile
+                .iter()
+                .map(|row| row.iter().map(|p| if *p { '#' } else { '.' }).collect())
+                .collect();
+            format!("Tile {}:\n{}\n", id, rows.join("\n"))
+        })
+        .collect();
+    return Generated {
+        input: input.join("\n"),
+        a: corners.to_string(),
+        b: roughness.to_string(),
+    };
+}
+
+/// A random image with sea monsters that do not overlap, all facing the same way. Returns the
+/// image and the number of pixels that are not part of a sea monster.
+fn image(rng: &mut Rng, size: usize) -> (Image, usize) {
+    let monster: Vec<(usize, usize)> = MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| (y, x))
+        })
+        .collect();
+    let (height, width) = (MONSTER.len(), MONSTER[0].len());
+
+    loop {
+        let mut image: Image = (0..size)
+            .map(|_| (0..size).map(|_| rng.chance(0.35)).collect())
+            .collect();
+        if size >= width {
+            // Keep the boxes around the planted sea monsters apart.
+            let mut planted: Vec<(usize, usize)> = Vec::new();
+            for _ in 0..(size * size / 400).max(1) {
+                let (y, x) = (rng.index(size - height + 1), rng.index(size - width + 1));
+                if planted
+                    .iter()
+                    .any(|(py, px)| py.abs_diff(y) < height && px.abs_diff(x) < width)
+                {
+                    continue;
+                }
+                planted.push((y, x));
+                for (dy, dx) in &monster {
+                    image[y + dy][x + dx] = true;
+                }
+            }
+        }
+
+        // Only one orientation may have sea monsters, and they may not share pixels.
+        let mut found = Vec::new();
+        for orientation in 0..8 {
+            let oriented = orient(&image, orientation);
+            let mut pixels = HashSet::new();
+            let mut count = 0;
+            for y in 0..=size.saturating_sub(height) {
+                for x in 0..=size.saturating_sub(width) {
+                    if size >= width && monster.iter().all(|(dy, dx)| oriented[y + dy][x + dx]) {
+                        pixels.extend(monster.iter().map(|(dy, dx)| (y + dy, x + dx)));
+                        count += 1;
+                    }
+                }
+            }
+            if count > 0 {
+                found.push((count, pixels.len()));
+            }
+        }
+        match found[..] {
+            [] => {}
+            [(count, pixels)] if pixels == count * monster.len() => {}
+            _ => continue,
+        }
+
+        let waves = image.iter().flatten().filter(|p| **p).count();
+        let monsters = found.first().map_or(0, |f| f.1);
+        return (image, waves - monsters);
+    }
+}
+
+/// One of the eight rotations and reflections of a square.
+fn orient(square: &[Vec<bool>], orientation: usize) -> Vec<Vec<bool>> {
+    let n = square.len();
+    let mut result = square.to_vec();
+    if orientation & 4 != 0 {
+        result = (0..n)
+            .map(|y| (0..n).map(|x| result[x][y]).collect())
+            .collect();
+    }
+    for _ in 0..orientation & 3 {
+        result = (0..n)
+            .map(|y| (0..n).map(|x| result[n - 1 - x][y]).collect())
+            .collect();
+    }
+    return result;
+}
@@ -0,0 +1,125 @@
+use super::{Generated, Rng};
+use std::collections::{BTreeMap, HashSet};
+
+const ALLERGENS: [&str; 9] = [
+    "dairy",
+    "eggs",
+    "fish",
+    "nuts",
+    "peanuts",
+    "sesame",
+    "shellfish",
+    "soy",
+    "wheat",
+];
+
+/// Recipes that list some of the allergens of their ingredients. Inputs where the allergens
+/// cannot be pinned down one at a time are thrown away.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    let count = (size / 3 + 1).min(ALLERGENS.len());
+    loop {
+        let mut allergens = ALLERGENS.to_vec();
+        rng.shuffle(&mut allergens);
+        allergens.truncate(count);
+
+        let ingredients: Vec<String> = (0..count * 6).map(|_| word(rng)).collect();
+        let unique: HashSet<&String> = ingredients.iter().collect();
+        if unique.len() < ingredients.len() {
+            continue;
+        }
+        // The first ingredients contain the allergens, in the same order.
+        let (dangerous, safe) = ingredients.split_at(count);
+
+        let mut recipes: Vec<(Vec<&str>, Vec<&str>)> = Vec::new();
+        for _ in 0..size {
+            let mut listed: Vec<usize> = (0..count).filter(|_| rng.chance(0.3)).collect();
+            if listed.is_empty() {
+                listed.push(rng.index(count));
+            }
+            let mut contents: Vec<&str> = Vec::new();
+            for (a, ingredient) in dangerous.iter().enumerate() {
+                // Allergens are not always listed.
+                if listed.contains(&a) || rng.chance(0.3) {
+                    contents.push(ingredient);
+                }
+            }
+            contents.extend(safe.iter().filter(|_| rng.chance(0.4)).map(|s| s.as_str()));
+            rng.shuffle(&mut contents);
+            recipes.push((contents, listed.iter().map(|a| allergens[*a]).collect()));
+        }
+
+        let mapping = match deduce(&recipes, &allergens) {
+            Some(m) => m,
+            None => continue,
+        };
+        let mut expected: BTreeMap<&str, &str> = BTreeMap::new();
+        for (a, allergen) in allergens.iter().enumerate() {
+            expected.insert(allergen, &dangerous[a]);
+        }
+        if mapping != expected {
+            continue;
+        }
+
+        let appearances = recipes
+            .iter()
+            .flat_map(|(contents, _)| contents)
+            .filter(|i| !dangerous.iter().any(|d| d == *i))
+            .count();
+        let input = recipes
+            .iter()
+            .map(|(contents, listed)| {
+                format!("{} (contains {})\n", contents.join(" "), listed.join(", "))
+            })
+            .collect();
+        let canonical: Vec<&str> = mapping.values().copied().collect();
+        return Generated {
+            input,
+            a: appearances.to_string(),
+            b: canonical.join(","),
+        };
+    }
+}
+
+fn word(rng: &mut Rng) -> String {
+    return (0..rng.range(4, 8))
+        .map(|_| (b'a' + rng.index(26) as u8) as char)
+        .collect();
+}
+
+/// Narrow down the ingredients of every allergen to those in all recipes that list it, then pin
+/// them down one at a time. `None` if that gets stuck.
+fn deduce<'a>(
+    recipes: &[(Vec<&'a str>, Vec<&'a str>)],
+    allergens: &[&'a str],
+) -> Option<BTreeMap<&'a str, &'a str>> {
+    let mut candidates: BTreeMap<&str, HashSet<&str>> = BTreeMap::new();
+    for allergen in allergens {
+        let mut possible: Option<HashSet<&str>> = None;
+        for (contents, listed) in recipes {
+            if listed.contains(allergen) {
+                let contents: HashSet<&str> = contents.iter().copied().collect();
+                possible = Some(match possible {
+                    Some(p) => p.intersection(&contents).copied().collect(),
+                    None => contents,
+                });
+            }
+        }
+        candidates.insert(allergen, possible?);
+    }
+
+    let mut mapping = BTreeMap::new();
+    while mapping.len() < allergens.len() {
+        let (allergen, ingredient) = candidates
+            .iter()
+            .find(|(a, c)| c.len() == 1 && !mapping.contains_key(*a))
+            .map(|(a, c)| (*a, *c.iter().next().unwrap()))?;
+        mapping.insert(allergen, ingredient);
+        for c in candidates.values_mut() {
+            if c.len() > 1 {
+                c.remove(ingredient);
+            }
+        }
+    }
+    return Some(mapping);
+}
@@ -0,0 +1,84 @@
+use super::{Generated, Rng};
+use std::collections::{HashSet, VecDeque};
+
+/// Give up on games of plain Combat that take this many rounds, they may never end.
+const MAX_ROUNDS: usize = 100_000;
+
+/// Two decks of different cards, dealt from a shuffled pile.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    loop {
+        let mut cards: Vec<usize> = (1..=size * 2).collect();
+        rng.shuffle(&mut cards);
+        let one: VecDeque<usize> = cards[..size].iter().copied().collect();
+        let two: VecDeque<usize> = cards[size..].iter().copied().collect();
+
+        let a = match combat(one.clone(), two.clone()) {
+            Some(a) => a,
+            None => continue,
+        };
+        let deck = |cards: &VecDeque<usize>| -> String {
+            return cards.iter().map(|c| format!("{}\n", c)).collect();
+        };
+        return Generated {
+            input: format!("Player 1:\n{}\nPlayer 2:\n{}", deck(&one), deck(&two)),
+            a: a.to_string(),
+            b: score(&recursive_combat(one, two).1).to_string(),
+        };
+    }
+}
+
+fn score(deck: &VecDeque<usize>) -> usize {
+    return deck
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, c)| (i + 1) * c)
+        .sum();
+}
+
+/// The score of the winner, `None` if the game takes too long.
+fn combat(mut one: VecDeque<usize>, mut two: VecDeque<usize>) -> Option<usize> {
+    for _ in 0..MAX_ROUNDS {
+        if one.is_empty() || two.is_empty() {
+            return Some(score(if one.is_empty() { &two } else { &one }));
+        }
+        let (a, b) = (one.pop_front().unwrap(), two.pop_front().unwrap());
+        if a > b {
+            one.extend([a, b]);
+        } else {
+            two.extend([b, a]);
+        }
+    }
+    return None;
+}
+
+/// Whether player 1 wins, with the deck of the winner.
+fn recursive_combat(mut one: VecDeque<usize>, mut two: VecDeque<usize>) -> (bool, VecDeque<usize>) {
+    let mut seen = HashSet::new();
+    while !one.is_empty() && !two.is_empty() {
+        if !seen.insert((one.clone(), two.clone())) {
+            return (true, one);
+        }
+        let (a, b) = (one.pop_front().unwrap(), two.pop_front().unwrap());
+        let one_wins = if one.len() >= a && two.len() >= b {
+            recursive_combat(
+                one.iter().take(a).copied().collect(),
+                two.iter().take(b).copied().collect(),
+            )
+            .0
+        } else {
+            a > b
+        };
+        if one_wins {
+            one.extend([a, b]);
+        } else {
+            two.extend([b, a]);
+        }
+    }
+    return if two.is_empty() {
+        (true, one)
+    } else {
+        (false, two)
+    };
+}
@@ -0,0 +1,69 @@
+use super::{Generated, Rng};
+use std::ops::RangeInclusive;
+
+/// Single digit labels, and enough cups to pick up three.
+pub const SIZES: RangeInclusive<usize> = 5..=9;
+
+/// A shuffled row of cups labelled from 1, with at least five so that a move can be made.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut cups: Vec<usize> = (1..=size.clamp(*SIZES.start(), *SIZES.end())).collect();
+    rng.shuffle(&mut cups);
+
+    let after_one = |next: &[usize], count: usize| -> Vec<usize> {
+        let mut labels = vec![next[1]];
+        while labels.len() < count {
+            labels.push(next[*labels.last().unwrap()]);
+        }
+        return labels;
+    };
+
+    let next = play(&cups, cups.len(), 100);
+    let a: String = after_one(&next, cups.len() - 1)
+        .iter()
+        .map(|c| c.to_string())
+        .collect();
+    let next = play(&cups, 1_000_000, 10_000_000);
+    let b: usize = after_one(&next, 2).iter().product();
+
+    let input: String = cups.iter().map(|c| c.to_string()).collect();
+    return Generated {
+        input: format!("{}\n", input),
+        a,
+        b: b.to_string(),
+    };
+}
+
+/// Play the crab's game with the cups padded up to `total`, returns the label of the cup after
+/// every cup.
+fn play(cups: &[usize], total: usize, moves: usize) -> Vec<usize> {
+    let mut order = cups.to_vec();
+    order.extend(cups.len() + 1..=total);
+    let mut next = vec![0; total + 1];
+    for (i, cup) in order.iter().enumerate() {
+        next[*cup] = order[(i + 1) % total];
+    }
+
+    let mut current = order[0];
+    for _ in 0..moves {
+        let first = next[current];
+        let second = next[first];
+        let third = next[second];
+        next[current] = next[third];
+
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                total
+            } else {
+                destination - 1
+            };
+            if destination != first && destination != second && destination != third {
+                break;
+            }
+        }
+        next[third] = next[destination];
+        next[destination] = first;
+        current = next[current];
+    }
+    return next;
+}
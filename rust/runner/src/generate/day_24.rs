@@ -0,0 +1,52 @@
+use super::{Generated, Rng};
+use std::collections::{HashMap, HashSet};
+
+/// Directions with their steps in cube coordinates.
+const DIRECTIONS: [(&str, (i64, i64, i64)); 6] = [
+    ("e", (1, -1, 0)),
+    ("w", (-1, 1, 0)),
+    ("ne", (1, 0, -1)),
+    ("nw", (0, 1, -1)),
+    ("se", (0, -1, 1)),
+    ("sw", (-1, 0, 1)),
+];
+
+/// Random walks from the reference tile, short enough that some tiles get flipped twice.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut black = HashSet::new();
+    for _ in 0..size.max(1) {
+        let mut tile = (0, 0, 0);
+        for _ in 0..rng.range(1, 20) {
+            let (name, (x, y, z)) = rng.choose(&DIRECTIONS);
+            input += name;
+            tile = (tile.0 + x, tile.1 + y, tile.2 + z);
+        }
+        input += "\n";
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    }
+
+    let a = black.len();
+    for _ in 0..100 {
+        let mut counts: HashMap<(i64, i64, i64), usize> = HashMap::new();
+        for tile in &black {
+            for (_, (x, y, z)) in &DIRECTIONS {
+                *counts
+                    .entry((tile.0 + x, tile.1 + y, tile.2 + z))
+                    .or_insert(0) += 1;
+            }
+        }
+        black = counts
+            .into_iter()
+            .filter(|(tile, count)| *count == 2 || (*count == 1 && black.contains(tile)))
+            .map(|(tile, _)| tile)
+            .collect();
+    }
+    return Generated {
+        input,
+        a: a.to_string(),
+        b: black.len().to_string(),
+    };
+}
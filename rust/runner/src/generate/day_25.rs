@@ -0,0 +1,33 @@
+use super::{Generated, Rng};
+use std::ops::RangeInclusive;
+
+const MODULUS: u64 = 20201227;
+const SUBJECT: u64 = 7;
+/// Loop sizes that a brute force search still finds quickly.
+pub const SIZES: RangeInclusive<usize> = 1..=24;
+
+/// Public keys for random loop sizes below `2^size`.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let high = (1i64 << size.clamp(*SIZES.start(), *SIZES.end())) - 1;
+    let card = rng.range(1, high) as u64;
+    let door = rng.range(1, high) as u64;
+
+    return Generated {
+        input: format!(
+            "{}\n{}\n",
+            transform(SUBJECT, card),
+            transform(SUBJECT, door)
+        ),
+        a: transform(transform(SUBJECT, door), card).to_string(),
+        b: "Merry Christmas!".to_string(),
+    };
+}
+
+/// Transform a subject number with a number of loops, one loop at a time like the handshake.
+fn transform(subject: u64, loops: u64) -> u64 {
+    let mut value = 1;
+    for _ in 0..loops {
+        value = value * subject % MODULUS;
+    }
+    return value;
+}
@@ -0,0 +1,294 @@
+//! Random puzzle inputs with known answers, for stress and regression testing.
+//!
+//! Every day has a generator that builds a valid input from a seed and a size, and computes the
+//! answers with a reference solver that is written independently of the solution in the day.
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+
+use aoc::Error;
+use std::ops::RangeInclusive;
+
+/// A generated input, with the answers to both parts.
+#[derive(Debug)]
+pub struct Generated {
+    pub input: String,
+    pub a: String,
+    pub b: String,
+}
+
+pub struct Generator {
+    pub day: u8,
+    /// What the size counts, e.g. "entries", shown when writing an input.
+    pub unit: &'static str,
+    pub default_size: usize,
+    /// The sizes the generator can build an input of.
+    pub sizes: RangeInclusive<usize>,
+    generate: Generate,
+}
+
+type Generate = fn(&mut Rng, usize) -> Generated;
+
+impl Generator {
+    pub fn generate(&self, seed: u64, size: usize) -> Generated {
+        return (self.generate)(&mut Rng::new(seed ^ u64::from(self.day) << 56), size);
+    }
+
+    /// Check a requested size, as the generators would quietly build a different one.
+    pub fn check_size(&self, size: usize) -> Result<(), Error> {
+        if self.sizes.contains(&size) {
+            return Ok(());
+        }
+        let (low, high) = (self.sizes.start(), self.sizes.end());
+        return Err(Error::Param(match *high {
+            usize::MAX => format!(
+                "the size has to be at least {} {}, not {}",
+                low, self.unit, size
+            ),
+            _ => format!(
+                "the size has to be from {} to {} {}, not {}",
+                low, high, self.unit, size
+            ),
+        }));
+    }
+}
+
+/// The sizes of generators that can build an input of any positive size.
+const ANY: RangeInclusive<usize> = 1..=usize::MAX;
+
+/// The name of the input set for a seed, e.g. `seed-42` for `day_14.seed-42.txt`.
+pub fn seed_set(seed: u64) -> String {
+    return format!("seed-{}", seed);
+}
+
+pub fn generator(day: u8) -> Option<Generator> {
+    let (unit, default_size, sizes, generate): (
+        &'static str,
+        usize,
+        RangeInclusive<usize>,
+        Generate,
+    ) = match day {
+        1 => ("entries", 200, day_01::SIZES, day_01::generate),
+        2 => ("passwords", 1000, ANY, day_02::generate),
+        3 => ("rows", 323, ANY, day_03::generate),
+        4 => ("passports", 250, ANY, day_04::generate),
+        5 => ("boarding passes", 800, day_05::SIZES, day_05::generate),
+        6 => ("groups", 450, ANY, day_06::generate),
+        7 => ("bag colors", 500, day_07::SIZES, day_07::generate),
+        8 => ("instructions", 600, day_08::SIZES, day_08::generate),
+        9 => ("numbers", 1000, day_09::SIZES, day_09::generate),
+        10 => ("adapters", 100, ANY, day_10::generate),
+        11 => ("rows of seats", 90, ANY, day_11::generate),
+        12 => ("actions", 750, ANY, day_12::generate),
+        13 => ("buses", 9, ANY, day_13::generate),
+        14 => ("masks", 100, ANY, day_14::generate),
+        15 => ("starting numbers", 7, day_15::SIZES, day_15::generate),
+        16 => ("fields", 20, day_16::SIZES, day_16::generate),
+        17 => ("rows of cubes", 8, ANY, day_17::generate),
+        18 => ("expressions", 370, ANY, day_18::generate),
+        19 => ("messages", 400, ANY, day_19::generate),
+        20 => ("tiles per side", 12, day_20::SIZES, day_20::generate),
+        21 => ("recipes", 40, ANY, day_21::generate),
+        22 => ("cards per deck", 25, ANY, day_22::generate),
+        23 => ("cups", 9, day_23::SIZES, day_23::generate),
+        24 => ("tiles", 400, ANY, day_24::generate),
+        25 => ("loop size bits", 20, day_25::SIZES, day_25::generate),
+        _ => return None,
+    };
+    return Some(Generator {
+        day,
+        unit,
+        default_size,
+        sizes,
+        generate,
+    });
+}
+
+/// A small deterministic random number generator (SplitMix64), so that a seed gives the same
+/// input on every platform and with every version of the dependencies.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return z ^ (z >> 31);
+    }
+
+    /// A number from `low` up to and including `high`.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "Empty range {}..={}", low, high);
+        let span = (high - low) as u64 + 1;
+        return low + (self.next_u64() % span) as i64;
+    }
+
+    /// An index into a collection of `len` items.
+    pub fn index(&mut self, len: usize) -> usize {
+        return self.range(0, len as i64 - 1) as usize;
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        return ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p;
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.index(items.len())];
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::registry;
+    use aoc::Part;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[test]
+    fn deterministic() {
+        let generator = generator(1).unwrap();
+        assert_eq!(
+            generator.generate(7, 20).input,
+            generator.generate(7, 20).input
+        );
+        assert_ne!(
+            generator.generate(7, 20).input,
+            generator.generate(8, 20).input
+        );
+    }
+
+    #[test]
+    fn sizes() {
+        for day in 1..=25 {
+            let generator = generator(day).unwrap();
+            assert!(generator.check_size(generator.default_size).is_ok());
+        }
+        assert_eq!(
+            generator(20)
+                .unwrap()
+                .check_size(13)
+                .unwrap_err()
+                .to_string(),
+            "bad parameter: the size has to be from 2 to 12 tiles per side, not 13"
+        );
+    }
+
+    /// The solutions have to agree with the reference solvers on small generated inputs.
+    #[test]
+    fn solutions_agree() {
+        let registry = registry();
+        for solution in registry.days() {
+            let generator = generator(solution.day()).unwrap();
+            // Day 15 and 23 always play millions of rounds in part B, size does not help.
+            let parts: &[Part] = match solution.day() {
+                15 | 23 => &[Part::A],
+                _ => &[Part::A, Part::B],
+            };
+            for seed in 0..3 {
+                let size = (generator.default_size / 4).max(1);
+                let generated = generator.generate(seed, size);
+                for part in parts {
+                    let expected = match part {
+                        Part::A => &generated.a,
+                        Part::B => &generated.b,
+                    };
+                    let answer = solution.solve(&generated.input, *part);
+                    assert_eq!(
+                        answer.as_ref().ok(),
+                        Some(expected),
+                        "day {} part {} seed {}: {:?}\n{}",
+                        solution.day(),
+                        part,
+                        seed,
+                        answer,
+                        generated.input
+                    );
+                }
+            }
+        }
+    }
+
+    /// Damage a generated input in a few random places.
+    fn mutate(rng: &mut Rng, input: &str) -> String {
+        const NOISE: [char; 24] = [
+            '0', '1', '9', '-', '+', 'a', 'x', 'X', '#', '.', ':', ',', ' ', '\n', '(', ')', '|',
+            '"', '[', ']', '=', '\u{e9}', '\0', '\r',
+        ];
+        let mut chars: Vec<char> = input.chars().collect();
+        for _ in 0..rng.range(1, 4) {
+            let at = rng.index(chars.len() + 1);
+            match rng.range(0, 3) {
+                0 if at < chars.len() => chars[at] = *rng.choose(&NOISE),
+                1 => chars.insert(at, *rng.choose(&NOISE)),
+                2 => chars.truncate(at),
+                _ => {
+                    let end = (at + rng.index(20)).min(chars.len());
+                    chars.drain(at..end);
+                }
+            }
+        }
+        return chars.into_iter().collect();
+    }
+
+    /// Parsers have to return an error on damaged or random inputs, never panic.
+    #[test]
+    fn parsers_never_panic() {
+        let registry = registry();
+        let mut rng = Rng::new(0);
+        for solution in registry.days() {
+            // Generating is the slow part, as the reference solvers run on every input.
+            let generated = generator(solution.day()).unwrap().generate(0, 3);
+            for i in 0..200 {
+                let input = if i % 10 == 0 {
+                    mutate(&mut rng, "")
+                } else {
+                    mutate(&mut rng, &generated.input)
+                };
+                let parsed = catch_unwind(AssertUnwindSafe(|| solution.parse(&input).is_ok()));
+                assert!(
+                    parsed.is_ok(),
+                    "day {} panics parsing {:?}",
+                    solution.day(),
+                    input
+                );
+            }
+        }
+    }
+}
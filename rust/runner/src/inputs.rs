@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The name of the set for the conventional input of a day.
+pub const DEFAULT_SET: &str = "input";
+
+/// An input file for a day, `set` tells it apart from other inputs for the same day.
+#[derive(Debug, PartialEq)]
+pub struct Input {
+    pub set: String,
+    pub path: PathBuf,
+}
+
+/// The conventional location of the input for a day, e.g. `inputs/day_14.txt`.
+pub fn input_path(input_dir: &Path, day: u8) -> PathBuf {
+    return input_dir.join(format!("day_{:02}.txt", day));
+}
+
+/// The location of another input set for a day, e.g. `inputs/day_14.example.txt`.
+pub fn set_path(input_dir: &Path, day: u8, set: &str) -> PathBuf {
+    return input_dir.join(format!("day_{:02}.{}.txt", day, set));
+}
+
+/// The set of a file, `day_14.example.txt` is the `example` set and any other file the default one.
+pub fn set_name(path: &Path) -> String {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    return match name.strip_suffix(".txt").and_then(|n| n.split_once('.')) {
+        Some((day, set)) if day.starts_with("day_") && !set.is_empty() => set.to_string(),
+        _ => DEFAULT_SET.to_string(),
+    };
+}
+
+/// Find the inputs for a day, the default set first and then e.g. `day_14.example.txt` in order.
+pub fn discover(input_dir: &Path, day: u8) -> Vec<Input> {
+    let mut inputs = Vec::new();
+
+    let default = input_path(input_dir, day);
+    if default.exists() {
+        inputs.push(Input {
+            set: DEFAULT_SET.to_string(),
+            path: default,
+        });
+    }
+
+    let prefix = format!("day_{:02}.", day);
+    let mut others: Vec<Input> = fs::read_dir(input_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            let name = p.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            name.starts_with(&prefix) && name.ends_with(".txt") && name != format!("{}txt", prefix)
+        })
+        .map(|path| Input {
+            set: set_name(&path),
+            path,
+        })
+        .collect();
+    others.sort_by(|a, b| a.set.cmp(&b.set));
+    inputs.extend(others);
+
+    return inputs;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_names() {
+        assert_eq!(set_name(Path::new("inputs/day_14.txt")), "input");
+        assert_eq!(set_name(Path::new("inputs/day_14.example.txt")), "example");
+        assert_eq!(set_name(Path::new("/tmp/puzzle.txt")), "input");
+        assert_eq!(
+            set_name(&set_path(Path::new("inputs"), 1, "seed-7")),
+            "seed-7"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use crate::Answer;
+use aoc::{Error, Part, Runnable};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// A solved part, e.g. `{"day":10,"part":"A","set":"input","answer":"35","type":"usize",...}`.
+pub fn answer(
+    solution: &dyn Runnable,
+    set: &str,
+    answer: &Answer,
+    parse_elapsed: Duration,
+) -> Value {
+    return json!({
+        "day": solution.day(),
+        "part": answer.part.to_string(),
+        "set": set,
+        "answer": answer.result.as_ref().ok(),
+        "type": solution.answer_type(answer.part),
+        "elapsed_ns": answer.elapsed.as_nanos() as u64,
+        "parse_ns": parse_elapsed.as_nanos() as u64,
+    });
+}
+
+/// A part that failed, or a whole input when `part` is `None`.
+pub fn error(day: u8, set: &str, part: Option<Part>, error: &Error) -> Value {
+    return json!({
+        "day": day,
+        "part": part.map(|p| p.to_string()),
+        "set": set,
+        "error": error.to_string(),
+    });
+}
@@ -0,0 +1,464 @@
+mod bench;
+mod cli;
+mod days;
+mod expect;
+#[cfg(test)]
+mod fixtures;
+mod generate;
+mod inputs;
+mod json;
+mod params;
+
+use aoc::budget::{self, Budget, Progress};
+use aoc::{info, parallel, Error, Part, Registry, Runnable};
+use bench::{change, format_duration, measure, Baseline};
+use cli::{Command, Days, Run};
+use expect::{Expectations, Verdict};
+use inputs::{discover, input_path, set_name, set_path, Input, DEFAULT_SET};
+use params::Params;
+use std::fs;
+use std::process;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+type Selection<'a> = Vec<(&'a dyn Runnable, Vec<Input>)>;
+
+/// The answers to an input with the time spent parsing it, or why it could not be parsed.
+type Solved = Result<(Duration, Vec<Answer>), Error>;
+
+/// The answer to a part of a day, with the time it took to solve it.
+pub struct Answer {
+    pub part: Part,
+    pub result: Result<String, Error>,
+    pub elapsed: Duration,
+}
+
+/// Pair the requested days with their inputs, returns whether all of them were found.
+fn select<'a>(registry: &'a Registry, run: &Run, all_sets: bool) -> (Selection<'a>, bool) {
+    let find = |day: u8| match &run.input {
+        Some(p) => vec![Input {
+            set: set_name(p),
+            path: p.clone(),
+        }],
+        None if all_sets => discover(&run.input_dir, day),
+        None => discover(&run.input_dir, day)
+            .into_iter()
+            .filter(|i| i.set == DEFAULT_SET)
+            .collect(),
+    };
+
+    let mut selected = Vec::new();
+    let mut success = true;
+    match &run.days {
+        Days::All => {
+            for solution in registry.days() {
+                let inputs = find(solution.day());
+                if inputs.is_empty() {
+                    info!(
+                        "Skipping day {}, there is no {}",
+                        solution.day(),
+                        input_path(&run.input_dir, solution.day()).display()
+                    );
+                    continue;
+                }
+                selected.push((solution, inputs));
+            }
+        }
+        Days::Some(days) => {
+            for day in days {
+                let solution = match registry.get(*day) {
+                    Some(s) => s,
+                    None => {
+                        eprintln!("Error: day {} is not solved yet", day);
+                        success = false;
+                        continue;
+                    }
+                };
+                let inputs = find(*day);
+                if inputs.is_empty() {
+                    eprintln!(
+                        "Error: day {}, there is no {}",
+                        day,
+                        input_path(&run.input_dir, *day).display()
+                    );
+                    success = false;
+                    continue;
+                }
+                selected.push((solution, inputs));
+            }
+        }
+    }
+    return (selected, success);
+}
+
+/// The limits for the solvers from the options.
+fn budget(run: &Run) -> Budget {
+    let mut budget = Budget::new();
+    if let Some(timeout) = run.timeout {
+        budget = budget.timeout(timeout);
+    }
+    if let Some(max_steps) = run.max_steps {
+        budget = budget.max_steps(max_steps);
+    }
+    return budget;
+}
+
+/// Parse an input once and solve the requested parts on it, returns the time spent parsing too.
+fn solve(
+    solution: &dyn Runnable,
+    input: &Input,
+    run: &Run,
+) -> Result<(Duration, Vec<Answer>), Error> {
+    let input = fs::read_to_string(&input.path)?;
+    let start = Instant::now();
+    let parsed = solution.parse(&input)?;
+    let parse_elapsed = start.elapsed();
+
+    let mut answers = Vec::new();
+    for part in &run.parts {
+        // Long running loops log their progress every second.
+        let (day, part_name) = (solution.day(), *part);
+        let budget = budget(run).progress(
+            Duration::from_secs(1),
+            Some(Arc::new(move |p: &Progress| {
+                info!("Day {:02} {}: {}", day, part_name, p)
+            })),
+        );
+        let start = Instant::now();
+        let result = budget::with(budget, || solution.run(parsed.as_ref(), *part));
+        answers.push(Answer {
+            part: *part,
+            result,
+            elapsed: start.elapsed(),
+        });
+    }
+    return Ok((parse_elapsed, answers));
+}
+
+/// Solve every selected input on `run.jobs` threads, and report each one in the order of the
+/// selection as soon as it and the inputs before it are solved.
+fn solve_all<F>(selected: &Selection, run: &Run, mut report: F)
+where
+    F: FnMut(&dyn Runnable, &Input, Solved) + Send,
+{
+    let inputs: Vec<(&dyn Runnable, &Input)> = selected
+        .iter()
+        .flat_map(|(solution, inputs)| inputs.iter().map(move |i| (*solution, i)))
+        .collect();
+    parallel::with_threads(run.jobs, || {
+        parallel::map_ordered(
+            &inputs,
+            |(solution, input)| solve(*solution, input, run),
+            |(solution, input), solved| report(*solution, input, solved),
+        )
+    });
+}
+
+fn run(registry: &Registry, run: &Run) -> bool {
+    let (selected, mut success) = select(registry, run, false);
+
+    solve_all(&selected, run, |solution, input, solved| {
+        let day = solution.day();
+        let (parse_elapsed, answers) = match solved {
+            Ok(a) => a,
+            Err(e) => {
+                if run.json {
+                    println!("{}", json::error(day, &input.set, None, &e));
+                } else {
+                    eprintln!("Error: day {}, {}: {}", day, input.path.display(), e);
+                }
+                success = false;
+                return;
+            }
+        };
+        for answer in answers {
+            success &= answer.result.is_ok();
+            if run.json {
+                let record = match &answer.result {
+                    Ok(_) => json::answer(solution, &input.set, &answer, parse_elapsed),
+                    Err(e) => json::error(day, &input.set, Some(answer.part), e),
+                };
+                println!("{}", record);
+                continue;
+            }
+            match &answer.result {
+                Ok(a) => println!("Day {:02} {}: {}", day, answer.part, a),
+                Err(e) => eprintln!("Error: day {}, part {}: {}", day, answer.part, e),
+            }
+        }
+    });
+    return success;
+}
+
+/// Compare the answers on every input set against the expectations, returns false on any failure.
+fn check(registry: &Registry, run: &Run) -> bool {
+    let path = match &run.answers {
+        Some(p) => p.clone(),
+        None => run.input_dir.join("answers.txt"),
+    };
+    let expectations = match Expectations::from_file(&path) {
+        Ok(e) => e,
+        Err(e) => {
+            eprintln!("Error: {}: {}", path.display(), e);
+            return false;
+        }
+    };
+
+    let (selected, success) = select(registry, run, true);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    solve_all(&selected, run, |solution, input, solved| {
+        let day = solution.day();
+        let (_, answers) = match solved {
+            Ok(a) => a,
+            Err(e) => {
+                println!("Day {:02} {}: FAIL, {}", day, input.set, e);
+                failed += run.parts.len();
+                return;
+            }
+        };
+        for Answer { part, result, .. } in answers {
+            let answer = match result {
+                Ok(a) => a,
+                Err(e) => {
+                    println!("Day {:02} {} {}: FAIL, {}", day, part, input.set, e);
+                    failed += 1;
+                    continue;
+                }
+            };
+            match expectations.check(day, part, &input.set, &answer) {
+                Verdict::Pass => {
+                    println!("Day {:02} {} {}: pass", day, part, input.set);
+                    passed += 1;
+                }
+                Verdict::Fail(expected) => {
+                    println!(
+                        "Day {:02} {} {}: FAIL, expected {} but got {}",
+                        day, part, input.set, expected, answer
+                    );
+                    failed += 1;
+                }
+                Verdict::Missing => {
+                    println!(
+                        "Day {:02} {} {}: missing, got {}",
+                        day, part, input.set, answer
+                    );
+                    missing += 1;
+                }
+            }
+        }
+    });
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    return success && failed == 0;
+}
+
+/// Time every stage of the given days, returns false on errors or regressions against the baseline.
+fn bench(registry: &Registry, run: &Run) -> bool {
+    let baseline = match &run.baseline {
+        Some(path) => match Baseline::from_file(path) {
+            Ok(b) => b,
+            Err(e) => {
+                eprintln!("Error: {}: {}", path.display(), e);
+                return false;
+            }
+        },
+        None => Baseline::default(),
+    };
+
+    let (selected, mut success) = select(registry, run, false);
+    let mut medians = Baseline::default();
+
+    for (solution, inputs) in selected {
+        let day = solution.day();
+        for input in inputs {
+            let timings = match fs::read_to_string(&input.path)
+                .map_err(Error::from)
+                .and_then(|i| {
+                    let budget = budget(run).progress(Duration::from_secs(1), None);
+                    budget::with(budget, || measure(solution, &i, &run.parts, run.runs))
+                }) {
+                Ok(t) => t,
+                Err(e) => {
+                    eprintln!("Error: day {}, {}: {}", day, input.path.display(), e);
+                    success = false;
+                    continue;
+                }
+            };
+
+            for (stage, stats) in timings {
+                let comparison = match baseline.get(day, stage) {
+                    Some(b) => {
+                        let change = change(b, stats.median);
+                        if change > run.threshold {
+                            success = false;
+                            format!("  {:+.1}% REGRESSION", change)
+                        } else {
+                            format!("  {:+.1}%", change)
+                        }
+                    }
+                    None => "".to_string(),
+                };
+                println!(
+                    "Day {:02} {:<5}  min {:>7}  median {:>7}  max {:>7}{}",
+                    day,
+                    stage,
+                    format_duration(stats.min),
+                    format_duration(stats.median),
+                    format_duration(stats.max),
+                    comparison
+                );
+                medians.insert(day, stage, stats.median);
+            }
+        }
+    }
+
+    if let Some(path) = &run.save {
+        if let Err(e) = fs::write(path, medians.to_string()) {
+            eprintln!("Error: {}: {}", path.display(), e);
+            success = false;
+        }
+    }
+    return success;
+}
+
+/// Write a generated input for every requested day and record its answers, returns false on
+/// errors.
+fn generate(registry: &Registry, run: &Run) -> bool {
+    let answers = match &run.answers {
+        Some(p) => p.clone(),
+        None => run.input_dir.join("answers.txt"),
+    };
+    let days: Vec<u8> = match &run.days {
+        Days::All => registry.days().map(|s| s.day()).collect(),
+        Days::Some(days) => days.clone(),
+    };
+    if let Err(e) = fs::create_dir_all(&run.input_dir) {
+        eprintln!("Error: {}: {}", run.input_dir.display(), e);
+        return false;
+    }
+
+    let mut success = true;
+    for day in days {
+        let generator = match generate::generator(day) {
+            Some(g) => g,
+            None => {
+                eprintln!("Error: day {} has no generator", day);
+                success = false;
+                continue;
+            }
+        };
+        let size = run.size.unwrap_or(generator.default_size);
+        if let Err(e) = generator.check_size(size) {
+            eprintln!("Error: day {}: {}", day, e);
+            success = false;
+            continue;
+        }
+        let generated = generator.generate(run.seed, size);
+
+        let set = generate::seed_set(run.seed);
+        let path = set_path(&run.input_dir, day, &set);
+        let written = fs::write(&path, &generated.input)
+            .map_err(Error::from)
+            .and_then(|_| Expectations::record(&answers, day, &set, &generated.a, &generated.b));
+        match written {
+            Ok(_) => println!("Wrote {} ({} {})", path.display(), size, generator.unit),
+            Err(e) => {
+                eprintln!("Error: day {}, {}: {}", day, path.display(), e);
+                success = false;
+            }
+        }
+    }
+    return success;
+}
+
+/// Override the puzzle parameters of the requested days, returns false on unknown parameters or
+/// invalid values.
+fn configure(registry: &mut Registry, run: &Run) -> bool {
+    let mut params = match &run.params_file {
+        Some(path) => match Params::from_file(path) {
+            Ok(p) => p,
+            Err(e) => {
+                eprintln!("Error: {}: {}", path.display(), e);
+                return false;
+            }
+        },
+        None => Params::default(),
+    };
+
+    // Parameters from the command line apply to every requested day that has them.
+    let days: Vec<u8> = match &run.days {
+        Days::All => registry.days().map(|s| s.day()).collect(),
+        Days::Some(days) => days.clone(),
+    };
+    for (name, value) in &run.params {
+        let matching: Vec<u8> = days
+            .iter()
+            .copied()
+            .filter(|d| {
+                registry
+                    .get(*d)
+                    .is_some_and(|s| s.params().iter().any(|(n, _)| n == name))
+            })
+            .collect();
+        if matching.is_empty() {
+            eprintln!("Error: none of the days has a parameter {:?}", name);
+            return false;
+        }
+        for day in matching {
+            params.values.push((day, name.clone(), value.clone()));
+        }
+    }
+
+    let mut success = true;
+    for (day, name, value) in params.values {
+        let result = match registry.get_mut(day) {
+            Some(s) => s.set_param(&name, &value),
+            None => Err(Error::Param(format!("day {} is not solved yet", day))),
+        };
+        if let Err(e) = result {
+            eprintln!("Error: day {}, {}", day, e);
+            success = false;
+        }
+    }
+    return success;
+}
+
+fn main() {
+    aoc::log::init();
+    let mut registry = days::registry();
+
+    let command = match cli::parse(std::env::args().skip(1)) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let success = match command {
+        Command::Run(r) => configure(&mut registry, &r) && run(&registry, &r),
+        Command::Check(r) => configure(&mut registry, &r) && check(&registry, &r),
+        Command::Bench(r) => configure(&mut registry, &r) && bench(&registry, &r),
+        Command::Generate(r) => generate(&registry, &r),
+        Command::List => {
+            for solution in registry.days() {
+                let params: Vec<String> = solution
+                    .params()
+                    .iter()
+                    .map(|(n, v)| format!("{}={}", n, v))
+                    .collect();
+                let line = format!("{} {}", solution.day(), params.join(" "));
+                println!("{}", line.trim_end());
+            }
+            true
+        }
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            true
+        }
+    };
+    if !success {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,70 @@
+use aoc::{parse_at, parse_lines_from_str, Error};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Puzzle parameters of several days, read from lines like `9 preamble 5`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Params {
+    pub values: Vec<(u8, String, String)>,
+}
+
+impl FromStr for Params {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Params, Self::Err> {
+        let mut values = Vec::new();
+
+        for (i, line) in parse_lines_from_str(input)?.iter().enumerate() {
+            let line = line.trim_end();
+            // Skip blank lines and comments.
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            // The value is the remainder of the line, so it may contain spaces.
+            let fields: Vec<&str> = line.splitn(3, ' ').collect();
+            if fields.len() < 3 || fields[2].is_empty() {
+                return Err(Error::parse(
+                    i + 1,
+                    1,
+                    line,
+                    "a parameter like \"<day> <name> <value>\"",
+                ));
+            }
+            let day: u8 = parse_at(fields[0], i + 1, 1, "a day")?;
+            values.push((day, fields[1].to_string(), fields[2].to_string()));
+        }
+
+        return Ok(Params { values });
+    }
+}
+
+impl Params {
+    pub fn from_file(path: &Path) -> Result<Params, Error> {
+        return Params::from_str(&fs::read_to_string(path)?);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let params = Params::from_str("# Variants\n9 preamble 5\n\n7 bag dark olive\n").unwrap();
+        assert_eq!(
+            params.values,
+            vec![
+                (9, "preamble".to_string(), "5".to_string()),
+                (7, "bag".to_string(), "dark olive".to_string())
+            ]
+        );
+
+        let error = Params::from_str("9 preamble 5\n9 preamble\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected \"9 preamble\", expected a parameter like \"<day> <name> <value>\""
+        );
+    }
+}